@@ -14,16 +14,6 @@ impl CaptureMaker {
             quitting: false,
         }
     }
-
-    pub fn run(&mut self) {
-        loop {
-            self.handle_command();
-
-            if self.quitting {
-                break;
-            }
-        }
-    }
 }
 
 impl Uci for CaptureMaker {
@@ -64,20 +54,15 @@ impl Uci for CaptureMaker {
         if let Some(command) = self.read_command() {
             match command {
                 UciCommand::Uci => {
-                    self.send_command(UciCommand::Id {
-                        name: "Capture Maker".to_string(),
-                        author: "Zirconium419122".to_string(),
-                    });
-                    self.send_command(UciCommand::UciOk);
-                }
-                UciCommand::Debug(debug) => {
-                    if debug {
-                        self.send_command(UciCommand::Info(Info {
-                            string: Some("Debug mode not supported!".to_string()),
-                            ..Default::default()
-                        }));
+                    for command in respond_uci("Capture Maker", "Zirconium419122") {
+                        self.send_command(command);
                     }
                 }
+                UciCommand::Debug(debug) if debug => {
+                    self.send_command(UciCommand::Info(
+                        Info::builder().string("Debug mode not supported!").build(),
+                    ));
+                }
                 UciCommand::IsReady => {
                     self.send_command(UciCommand::ReadyOk);
                 }
@@ -104,26 +89,25 @@ impl Uci for CaptureMaker {
                         let mut moves = Vec::new();
 
                         for mv in board.generate_moves_vec(board.occupancy(!board.side_to_move)) {
-                            if let Ok(_) = board.make_move_new(mv) {
+                            if board.make_move_new(mv).is_ok() {
                                 moves.push(mv);
                             }
                         }
 
                         if moves.is_empty() {
                             for mv in board.generate_moves_vec(!EMPTY) {
-                                if let Ok(_) = board.make_move_new(mv) {
+                                if board.make_move_new(mv).is_ok() {
                                     moves.push(mv);
                                 }
                             }
                         }
 
                         dbg!(&moves);
-                        let mv = moves[0].clone();
+                        let mv = moves[0];
 
-                        self.send_command(UciCommand::Info(Info {
-                            pv: Some(mv.to_string()),
-                            ..Default::default()
-                        }));
+                        self.send_command(UciCommand::Info(
+                            Info::builder().pv(vec![mv.to_string()]).build(),
+                        ));
                         self.send_command(UciCommand::BestMove {
                             best_move: mv.to_string(),
                             ponder: None,
@@ -136,6 +120,10 @@ impl Uci for CaptureMaker {
             }
         }
     }
+
+    fn should_quit(&self) -> bool {
+        self.quitting
+    }
 }
 
 fn main() {
@@ -17,16 +17,6 @@ impl RandomMoveMaker {
             quitting: false,
         }
     }
-
-    pub fn run(&mut self) {
-        loop {
-            self.handle_command();
-
-            if self.quitting {
-                break;
-            }
-        }
-    }
 }
 
 impl Uci for RandomMoveMaker {
@@ -67,20 +57,15 @@ impl Uci for RandomMoveMaker {
         if let Some(command) = self.read_command() {
             match command {
                 UciCommand::Uci => {
-                    self.send_command(UciCommand::Id {
-                        name: "Random Move Maker".to_string(),
-                        author: "Zirconium419122".to_string(),
-                    });
-                    self.send_command(UciCommand::UciOk);
-                }
-                UciCommand::Debug(debug) => {
-                    if debug {
-                        self.send_command(UciCommand::Info(Info {
-                            string: Some("Debug mode not supported!".to_string()),
-                            ..Default::default()
-                        }));
+                    for command in respond_uci("Random Move Maker", "Zirconium419122") {
+                        self.send_command(command);
                     }
                 }
+                UciCommand::Debug(debug) if debug => {
+                    self.send_command(UciCommand::Info(
+                        Info::builder().string("Debug mode not supported!").build(),
+                    ));
+                }
                 UciCommand::IsReady => {
                     self.send_command(UciCommand::ReadyOk);
                 }
@@ -107,17 +92,16 @@ impl Uci for RandomMoveMaker {
                         let mut moves = Vec::new();
 
                         for mv in board.generate_moves_vec(!EMPTY) {
-                            if let Ok(_) = board.make_move_new(mv) {
+                            if board.make_move_new(mv).is_ok() {
                                 moves.push(mv);
                             }
                         }
 
-                        let best_move = moves[self.rng.next_u32() as usize % moves.len()].clone();
+                        let best_move = moves[self.rng.next_u32() as usize % moves.len()];
 
-                        self.send_command(UciCommand::Info(Info {
-                            pv: Some(best_move.to_string()),
-                            ..Default::default()
-                        }));
+                        self.send_command(UciCommand::Info(
+                            Info::builder().pv(vec![best_move.to_string()]).build(),
+                        ));
                         self.send_command(UciCommand::BestMove {
                             best_move: best_move.to_string(),
                             ponder: None,
@@ -130,6 +114,10 @@ impl Uci for RandomMoveMaker {
             }
         }
     }
+
+    fn should_quit(&self) -> bool {
+        self.quitting
+    }
 }
 
 fn main() {
@@ -45,16 +45,6 @@ impl SimpleMoveMaker {
         }
     }
 
-    pub fn run(&mut self) {
-        loop {
-            self.handle_command();
-
-            if self.quitting {
-                break;
-            }
-        }
-    }
-
     pub fn search_base(board: &Board, depth: usize) -> (isize, Option<ChessMove>) {
         let mut max = isize::MIN;
         let mut best_move = None;
@@ -167,7 +157,7 @@ impl SimpleMoveMaker {
         score * perspective
     }
 
-    fn sort_moves(board: &Board, moves: &mut Vec<ChessMove>) {
+    fn sort_moves(board: &Board, moves: &mut [ChessMove]) {
         moves.sort_by_key(|mv| -Self::score_move(board, *mv));
     }
 
@@ -221,20 +211,17 @@ impl Uci for SimpleMoveMaker {
         if let Some(command) = self.read_command() {
             match command {
                 UciCommand::Uci => {
-                    self.send_command(UciCommand::Id {
-                        name: "Simple Move Maker".to_string(),
-                        author: "Zirconium419122".to_string(),
-                    });
-                    self.send_command(UciCommand::UciOk);
-                }
-                UciCommand::Debug(debug) => {
-                    if debug {
-                        self.send_command(UciCommand::Info(Info {
-                            string: Some("Debug mode not supported!".to_string()),
-                            ..Default::default()
-                        }));
+                    for command in respond_uci("Simple Move Maker", "Zirconium419122") {
+                        self.send_command(command);
                     }
                 }
+                UciCommand::Debug(debug) if debug => {
+                    self.send_command(UciCommand::Info(
+                        Info::builder()
+                            .string("Debug mode not supported!")
+                            .build(),
+                    ));
+                }
                 UciCommand::IsReady => {
                     self.send_command(UciCommand::ReadyOk);
                 }
@@ -258,7 +245,7 @@ impl Uci for SimpleMoveMaker {
                 }
                 UciCommand::Go { .. } => {
                     if let Some(ref board) = self.board {
-                        let (score, best_move) = Self::search_base(&board, SimpleMoveMaker::SEARCH_DEPTH);
+                        let (score, best_move) = Self::search_base(board, SimpleMoveMaker::SEARCH_DEPTH);
 
                         if let Some(best_move) = best_move {
                             if score.abs() >= SimpleMoveMaker::MATE_SCORE - 100 {
@@ -266,25 +253,31 @@ impl Uci for SimpleMoveMaker {
                                 let moves_to_mate = SimpleMoveMaker::MATE_SCORE - score.abs();
                                 let mate_in_moves = (moves_to_mate / 2) + 1;
 
-                                let mut score = Score::default();
-                                score.mate = Some(correction * mate_in_moves);
-
-                                self.send_command(UciCommand::Info(Info {
-                                    pv: Some(best_move.to_string()),
-                                    score: Some(score),
+                                let score = Score {
+                                    mate: Some(correction * mate_in_moves),
                                     ..Default::default()
-                                }));
+                                };
+
+                                self.send_command(UciCommand::Info(
+                                    Info::builder()
+                                        .pv(vec![best_move.to_string()])
+                                        .score(score)
+                                        .build(),
+                                ));
                             } else {
                                 let cp = score;
 
-                                let mut score = Score::default();
-                                score.cp = Some(cp);
-
-                                self.send_command(UciCommand::Info(Info {
-                                    pv: Some(best_move.to_string()),
-                                    score: Some(score),
+                                let score = Score {
+                                    cp: Some(cp),
                                     ..Default::default()
-                                }));
+                                };
+
+                                self.send_command(UciCommand::Info(
+                                    Info::builder()
+                                        .pv(vec![best_move.to_string()])
+                                        .score(score)
+                                        .build(),
+                                ));
                             }
                             self.send_command(UciCommand::BestMove {
                                 best_move: best_move.to_string(),
@@ -299,6 +292,10 @@ impl Uci for SimpleMoveMaker {
             }
         }
     }
+
+    fn should_quit(&self) -> bool {
+        self.quitting
+    }
 }
 
 fn main() {
@@ -1,13 +1,16 @@
 use core::fmt;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr};
 
-use crate::{file::File, rank::Rank, square::Square};
+use crate::{direction::Direction, file::File, rank::Rank, square::Square};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
 pub struct BitBoard(pub u64);
 
 pub const EMPTY: BitBoard = BitBoard(0);
 
+const NOT_FILE_A: u64 = !0x0101010101010101;
+const NOT_FILE_H: u64 = !0x8080808080808080;
+
 impl BitAnd for BitBoard {
     type Output = Self;
 
@@ -135,6 +138,11 @@ impl Not for &BitBoard {
     }
 }
 
+/// Shift the bits of the `BitBoard` left.
+///
+/// # Panics
+/// Like the shift on `u64`, this panics in debug builds if `rhs` is 64 or more. Use
+/// [`BitBoard::checked_shl`] if the shift might be out of range.
 impl Shl<usize> for BitBoard {
     type Output = Self;
 
@@ -144,6 +152,11 @@ impl Shl<usize> for BitBoard {
     }
 }
 
+/// Shift the bits of the `BitBoard` right.
+///
+/// # Panics
+/// Like the shift on `u64`, this panics in debug builds if `rhs` is 64 or more. Use
+/// [`BitBoard::checked_shr`] if the shift might be out of range.
 impl Shr<usize> for BitBoard {
     type Output = Self;
 
@@ -169,6 +182,24 @@ impl Iterator for BitBoard {
     }
 }
 
+/// Collect squares into a `BitBoard` with every one of them set.
+impl FromIterator<Square> for BitBoard {
+    fn from_iter<T: IntoIterator<Item = Square>>(iter: T) -> Self {
+        let mut bitboard = EMPTY;
+        bitboard.extend(iter);
+        bitboard
+    }
+}
+
+/// Set every square in the iterator on the `BitBoard`.
+impl Extend<Square> for BitBoard {
+    fn extend<T: IntoIterator<Item = Square>>(&mut self, iter: T) {
+        for square in iter {
+            *self |= BitBoard::from_square(square);
+        }
+    }
+}
+
 impl fmt::Display for BitBoard {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let mut s: String = "".to_string();
@@ -186,6 +217,18 @@ impl fmt::Display for BitBoard {
     }
 }
 
+impl fmt::LowerHex for BitBoard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for BitBoard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
 impl BitBoard {
     /// Construct a new bitboard from a `u64`.
     #[inline]
@@ -217,6 +260,36 @@ impl BitBoard {
         self.0.count_ones()
     }
 
+    /// Check if more than one bit is set in the `BitBoard`, cheaper than counting the bits.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::{BitBoard, EMPTY};
+    ///
+    /// assert!(BitBoard(0b101).more_than_one());
+    /// assert!(!BitBoard(0b100).more_than_one());
+    /// assert!(!EMPTY.more_than_one());
+    /// ```
+    #[inline]
+    pub fn more_than_one(&self) -> bool {
+        self.0 & self.0.wrapping_sub(1) != 0
+    }
+
+    /// Check if exactly one bit is set in the `BitBoard`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::{BitBoard, EMPTY};
+    ///
+    /// assert!(BitBoard(0b100).is_single());
+    /// assert!(!BitBoard(0b101).is_single());
+    /// assert!(!EMPTY.is_single());
+    /// ```
+    #[inline]
+    pub fn is_single(&self) -> bool {
+        self.0 != 0 && !self.more_than_one()
+    }
+
     /// Set the bit at `Square`.
     #[inline]
     pub fn set_bit(&mut self, square: Square) {
@@ -241,6 +314,66 @@ impl BitBoard {
         (self.0 & (1 << square.to_int())) == 0
     }
 
+    /// Check if the `BitBoard` contains `Square`, the same as [`BitBoard::is_set`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, square::Square};
+    ///
+    /// let bitboard = BitBoard::from_square(Square::E4);
+    ///
+    /// assert!(bitboard.contains(Square::E4));
+    /// assert!(!bitboard.contains(Square::E5));
+    /// ```
+    #[inline]
+    pub fn contains(&self, square: Square) -> bool {
+        self.is_set(square)
+    }
+
+    /// Check if every square set in the `BitBoard` is also set in `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::{BitBoard, EMPTY};
+    ///
+    /// assert!(BitBoard(0b0110).is_subset(BitBoard(0b1110)));
+    /// assert!(!BitBoard(0b0111).is_subset(BitBoard(0b1110)));
+    /// assert!(EMPTY.is_subset(EMPTY));
+    /// ```
+    #[inline]
+    pub fn is_subset(&self, other: BitBoard) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Check if every square set in `other` is also set in the `BitBoard`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::BitBoard;
+    ///
+    /// assert!(BitBoard(0b1110).is_superset(BitBoard(0b0110)));
+    /// assert!(!BitBoard(0b1110).is_superset(BitBoard(0b0111)));
+    /// ```
+    #[inline]
+    pub fn is_superset(&self, other: BitBoard) -> bool {
+        other.is_subset(*self)
+    }
+
+    /// Check if the `BitBoard` and `other` have any square in common.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::{BitBoard, EMPTY};
+    ///
+    /// assert!(BitBoard(0b0110).intersects(BitBoard(0b1100)));
+    /// assert!(!BitBoard(0b0011).intersects(BitBoard(0b1100)));
+    /// assert!(!EMPTY.intersects(!EMPTY));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: BitBoard) -> bool {
+        self.0 & other.0 != 0
+    }
+
     /// Check if the `BitBoard` is zero.
     #[inline]
     pub fn is_zero(&self) -> bool {
@@ -252,4 +385,135 @@ impl BitBoard {
     pub fn is_not_zero(&self) -> bool {
         self != &EMPTY
     }
+
+    /// Shift the bits of the `BitBoard` left, returning `EMPTY` if `rhs` is 64 or more.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::{BitBoard, EMPTY};
+    ///
+    /// assert_eq!(BitBoard(1).checked_shl(8), BitBoard(0x100));
+    /// assert_eq!(BitBoard(1).checked_shl(64), EMPTY);
+    /// ```
+    #[inline]
+    pub fn checked_shl(self, rhs: usize) -> BitBoard {
+        if rhs < 64 {
+            BitBoard(self.0 << rhs)
+        } else {
+            EMPTY
+        }
+    }
+
+    /// Shift the bits of the `BitBoard` right, returning `EMPTY` if `rhs` is 64 or more.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::{BitBoard, EMPTY};
+    ///
+    /// assert_eq!(BitBoard(0x100).checked_shr(8), BitBoard(1));
+    /// assert_eq!(BitBoard(u64::MAX).checked_shr(64), EMPTY);
+    /// ```
+    #[inline]
+    pub fn checked_shr(self, rhs: usize) -> BitBoard {
+        if rhs < 64 {
+            BitBoard(self.0 >> rhs)
+        } else {
+            EMPTY
+        }
+    }
+
+    /// Format the `BitBoard` as a zero-padded hexadecimal string, which is more compact than the
+    /// grid printed by `Display`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::bitboard::BitBoard;
+    ///
+    /// assert_eq!(BitBoard(0xFFFF00000000FFFF).to_hex(), "0xFFFF00000000FFFF");
+    /// assert_eq!(BitBoard(0xFF).to_hex(), "0x00000000000000FF");
+    /// ```
+    pub fn to_hex(self) -> String {
+        format!("{:#018X}", self)
+    }
+
+    /// Shift every set bit one square in the given [`Direction`]. Bits shifted off the board are
+    /// dropped instead of wrapping around to the other side.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, direction::Direction, square::Square};
+    ///
+    /// let bitboard = BitBoard::from_square(Square::E4);
+    ///
+    /// assert_eq!(bitboard.shift_dir(Direction::NorthWest), BitBoard::from_square(Square::D5));
+    /// assert_eq!(BitBoard::from_square(Square::H4).shift_dir(Direction::East), EMPTY);
+    /// ```
+    #[inline]
+    pub fn shift_dir(self, direction: Direction) -> BitBoard {
+        let bits = self.0;
+
+        BitBoard(match direction {
+            Direction::North => bits << 8,
+            Direction::NorthEast => (bits & NOT_FILE_H) << 9,
+            Direction::East => (bits & NOT_FILE_H) << 1,
+            Direction::SouthEast => (bits & NOT_FILE_H) >> 7,
+            Direction::South => bits >> 8,
+            Direction::SouthWest => (bits & NOT_FILE_A) >> 9,
+            Direction::West => (bits & NOT_FILE_A) >> 1,
+            Direction::NorthWest => (bits & NOT_FILE_A) << 7,
+        })
+    }
+
+    /// Smear every set bit towards the eighth rank, using Kogge-Stone fills.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, square::Square};
+    ///
+    /// let pawn = BitBoard::from_square(Square::E2);
+    ///
+    /// assert_eq!(pawn.fill_north(), BitBoard(0x1010101010101000));
+    /// ```
+    #[inline]
+    pub fn fill_north(self) -> BitBoard {
+        let mut bits = self.0;
+        bits |= bits << 8;
+        bits |= bits << 16;
+        bits |= bits << 32;
+        BitBoard(bits)
+    }
+
+    /// Smear every set bit towards the first rank, using Kogge-Stone fills.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, square::Square};
+    ///
+    /// let pawn = BitBoard::from_square(Square::E7);
+    ///
+    /// assert_eq!(pawn.fill_south(), BitBoard(0x0010101010101010));
+    /// ```
+    #[inline]
+    pub fn fill_south(self) -> BitBoard {
+        let mut bits = self.0;
+        bits |= bits >> 8;
+        bits |= bits >> 16;
+        bits |= bits >> 32;
+        BitBoard(bits)
+    }
+
+    /// Fill the whole file of every set bit.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, square::Square};
+    ///
+    /// let pawns = BitBoard::from_square(Square::A4) | BitBoard::from_square(Square::E2);
+    ///
+    /// assert_eq!(pawns.fill_file(), BitBoard(0x1111111111111111));
+    /// ```
+    #[inline]
+    pub fn fill_file(self) -> BitBoard {
+        self.fill_north() | self.fill_south()
+    }
 }
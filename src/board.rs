@@ -3,19 +3,35 @@ use std::{
     str::FromStr,
 };
 
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+
 use crate::{
     bitboard::{BitBoard, EMPTY},
     castling_rights::CastlingRights,
     chess_move::{ChessMove, MoveMetaData},
     color::{COLORS, Color},
     error::Error,
+    evaluation::Evaluator,
     file::File,
     magic::*,
-    piece::Piece,
+    piece::{PIECES, Piece},
     rank::Rank,
     square::{SQUARES, Square},
 };
 
+/// The capacity of the move lists from [`Board::generate_moves_arrayvec`], enough for any
+/// position.
+#[cfg(feature = "arrayvec")]
+pub const MAX_MOVES: usize = 256;
+
+/// The non-pawn material of both sides together, in centipawns, below which a [`Board`] is in the
+/// endgame, see [`Board::is_endgame`].
+pub const ENDGAME_MATERIAL: i32 = 2600;
+
+/// Marks the pinned pieces of a [`Board`] as unknown, see [`Board::make_move_fast`].
+const UNKNOWN_PINS: BitBoard = BitBoard(u64::MAX);
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
 pub struct UnmakeData {
     pub castling_rights: CastlingRights,
@@ -24,9 +40,95 @@ pub struct UnmakeData {
     pub pinned: BitBoard,
     pub check: u8,
     pub half_moves: u8,
+    pub full_moves: u16,
+}
+
+/// The six fields of a FEN, see [`Board::fen_fields`].
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct FenFields {
+    pub placement: String,
+    pub side_to_move: Color,
+    pub castling_rights: CastlingRights,
+    pub en_passant_square: Option<Square>,
+    pub half_moves: u16,
+    pub full_moves: u16,
+}
+
+impl FenFields {
+    /// Join the fields back into a FEN.
+    pub fn to_fen(&self) -> String {
+        let side_to_move = match self.side_to_move {
+            Color::White => 'w',
+            Color::Black => 'b',
+        };
+        let en_passant_square = match self.en_passant_square {
+            Some(en_passant_square) => en_passant_square.to_string(),
+            None => "-".to_string(),
+        };
+
+        format!(
+            "{} {} {} {} {} {}",
+            self.placement,
+            side_to_move,
+            self.castling_rights.to_fen(),
+            en_passant_square,
+            self.half_moves,
+            self.full_moves,
+        )
+    }
+}
+
+/// A [`ChessMove`] displayed in Standard Algebraic Notation, see [`Board::san_move`].
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct SanMove<'a> {
+    board: &'a Board,
+    mv: ChessMove,
+}
+
+impl std::fmt::Display for SanMove<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.board.to_san(self.mv))
+    }
+}
+
+/// The reason a [`ChessMove`] is illegal, see [`Board::why_illegal`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+pub enum IllegalReason {
+    /// There is no piece on the square the move starts from.
+    NoPiece,
+    /// The piece on the square the move starts from belongs to the opponent.
+    WrongColor,
+    /// The piece can't move to the destination square, even ignoring checks.
+    NotPseudoLegal,
+    /// The move would leave or put the king in check.
+    LeavesKingInCheck,
+    /// The king would castle out of, through or into check.
+    CastleThroughCheck,
+}
+
+/// Options restricting the moves generated by [`Board::generate_moves_with`]. The default
+/// generates every pseudo-legal move, like [`Board::generate_moves_vec`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+pub struct GenOptions {
+    /// Generate promotions to knights, bishops and rooks, and not only to queens.
+    pub underpromotions: bool,
+    /// Generate captures, including en passant.
+    pub captures: bool,
+    /// Generate moves that don't capture, including castling and quiet promotions.
+    pub quiets: bool,
+}
+
+impl Default for GenOptions {
+    fn default() -> GenOptions {
+        GenOptions {
+            underpromotions: true,
+            captures: true,
+            quiets: true,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy)]
 pub struct Board {
     pub pieces: [BitBoard; 6],    // 6 for both, compute white and black using occupancy
     pub occupancy: [BitBoard; 2], // white, black occupancy
@@ -34,7 +136,9 @@ pub struct Board {
     pub pinned: BitBoard,
     pub check: u8,
     pub half_moves: u8,
+    pub full_moves: u16,
     pub hash: u64,
+    pub material: [i32; 2],
     pub side_to_move: Color,
     pub castling_rights: CastlingRights,
     pub en_passant_square: Option<Square>,
@@ -42,10 +146,36 @@ pub struct Board {
 
 impl Default for Board {
     fn default() -> Self {
-        Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+        Board::standard()
+    }
+}
+
+/// Compare a [`Board`] with a FEN, see [`Board::same_position`]. An invalid FEN never compares
+/// equal.
+impl PartialEq<str> for Board {
+    fn eq(&self, other: &str) -> bool {
+        Board::try_from_fen(other).is_ok_and(|board| self.same_position(&board))
+    }
+}
+
+impl PartialEq<&str> for Board {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Two boards are equal if they have the same position, see [`Board::same_position`]. The
+/// cached `check` and `pinned` fields and the move counters are ignored.
+impl PartialEq for Board {
+    fn eq(&self, other: &Board) -> bool {
+        self.same_position(other)
     }
 }
 
+impl Eq for Board {}
+
+/// Hash the zobrist hash of the [`Board`], which only depends on the position. Like
+/// [`PartialEq`], boards reached through different move orders hash the same.
 impl Hash for Board {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.hash().hash(state);
@@ -94,14 +224,54 @@ impl Board {
             pinned: EMPTY,
             check: 0,
             half_moves: 0,
-            hash: 0,
+            full_moves: 1,
+            hash: Board::castling_hash(CastlingRights::new()),
+            material: [0; 2],
             side_to_move: Color::White,
             castling_rights: CastlingRights::new(),
             en_passant_square: None,
         }
     }
 
+    /// Create a empty `Board` which has no pieces in it. Alias for [`Board::new`].
+    /// ```
+    /// use chessframe::{board::Board, bitboard::EMPTY};
+    ///
+    /// assert_eq!(Board::empty(), Board::new());
+    /// assert_eq!(Board::empty().combined(), EMPTY);
+    /// ```
+    pub fn empty() -> Board {
+        Board::new()
+    }
+
+    /// Create a `Board` with the standard starting position.
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// assert_eq!(Board::standard(), Board::default());
+    /// ```
+    pub fn standard() -> Board {
+        Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+    }
+
+    /// Create a `Board` with the "Kiwipete" position, which is commonly used for perft testing.
+    /// ```
+    /// use chessframe::{bitboard::EMPTY, board::Board};
+    ///
+    /// let board = Board::kiwipete();
+    ///
+    /// assert_eq!(board.generate_moves_vec(!EMPTY).len(), 48);
+    /// ```
+    pub fn kiwipete() -> Board {
+        Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+    }
+
     /// Create a board from a FEN in the form of a `&str`.
+    ///
+    /// # Panics
+    /// Panics if the FEN is invalid, see [`Board::try_from_fen`].
+    ///
+    /// # Example
     /// ```
     /// use chessframe::board::Board;
     ///
@@ -110,94 +280,237 @@ impl Board {
     ///
     /// assert_eq!(board, Board::default());
     /// ```
-    #[rustfmt::skip]
     pub fn from_fen(fen: &str) -> Board {
+        match Board::try_from_fen(fen) {
+            Ok(board) => board,
+            Err(error) => panic!("{}: {}", error, fen),
+        }
+    }
+
+    /// Try to create a board from a FEN in the form of a `&str`, returns [`Error::InvalidFen`] if
+    /// the FEN is invalid.
+    ///
+    /// The halfmove clock and fullmove number may be left out, as is common in EPD, in which case
+    /// they default to `0 1`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, error::Error};
+    ///
+    /// let board = Board::try_from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -");
+    ///
+    /// assert_eq!(board, Ok(Board::default()));
+    /// assert_eq!(Board::try_from_fen("8/8/8/8 w - - 0 1"), Err(Error::InvalidFen));
+    /// ```
+    pub fn try_from_fen(fen: &str) -> Result<Board, Error> {
         let mut board = Board::new();
 
         let parts: Vec<&str> = fen.split_whitespace().collect();
-        assert_eq!(parts.len(), 6);
+        if parts.len() != 4 && parts.len() != 6 {
+            return Err(Error::InvalidFen);
+        }
 
-        board.parse_pieces(parts[0]);
+        board.parse_pieces(parts[0])?;
 
         board.combined = board.occupancy(Color::White) | board.occupancy(Color::Black);
 
-        let knights = get_knight_moves(board.pieces_color(Piece::King, !board.side_to_move).to_square())
-            & board.pieces_color(Piece::Knight, board.side_to_move);
-        board.check = (knights.count_ones() > 0) as u8;
+        if COLORS
+            .iter()
+            .any(|color| board.pieces_color(Piece::King, *color).count_ones() != 1)
+        {
+            return Err(Error::InvalidFen);
+        }
+
+        match parts[1] {
+            "w" => {}
+            "b" => board.flip_side_to_move(),
+            _ => return Err(Error::InvalidFen),
+        }
 
-        for color in COLORS {
-            let king_square = board.pieces_color(Piece::King, !color).to_square();
-            let attackers = board.occupancy(color) & ((get_bishop_moves(king_square, EMPTY) & (board.pieces(Piece::Bishop) | board.pieces(Piece::Queen)))
-                | (get_rook_moves(king_square, EMPTY) & (board.pieces(Piece::Rook) | board.pieces(Piece::Queen))));
+        board.set_castling_rights(CastlingRights::from_fen(parts[2]));
 
-            for square in attackers {
-                let between = get_between(square, king_square) & board.combined();
-                if between.count_ones() == 1 {
-                    board.pinned ^= between & board.occupancy(!color);
-                } else if between == EMPTY {
-                    board.check += 1;
-                }
+        board.side_to_move = !board.side_to_move;
+
+        board.parse_en_passant(parts[3])?;
+
+        board.side_to_move = !board.side_to_move;
+
+        if let [half_moves, full_moves] = parts[4..] {
+            board.half_moves = half_moves.parse::<u8>().map_err(|_| Error::InvalidFen)?;
+            board.full_moves = full_moves.parse::<u16>().map_err(|_| Error::InvalidFen)?;
+        }
+
+        board.update_check_and_pinned();
+
+        Ok(board)
+    }
+
+    /// Create a [`Board`] from an array of the piece on every square, indexed by
+    /// [`Square::to_index`], so `A1` first and `H8` last. See [`to_array`] for the inverse.
+    ///
+    /// The en passant square is only kept if a pawn of the side to move can capture on it, like
+    /// in [`try_from_fen`]. Returns [`Error::InvalidBoard`] unless both sides have exactly one
+    /// king.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, castling_rights::CastlingRights, color::Color, piece::Piece, square::Square};
+    ///
+    /// let mut squares = [None; 64];
+    /// squares[Square::E1.to_index()] = Some((Piece::King, Color::White));
+    /// squares[Square::E8.to_index()] = Some((Piece::King, Color::Black));
+    /// squares[Square::A1.to_index()] = Some((Piece::Rook, Color::White));
+    ///
+    /// let board = Board::from_array(squares, Color::White, CastlingRights::from_fen("Q"), None);
+    ///
+    /// assert_eq!(board, Ok(Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w Q - 0 1")));
+    /// ```
+    ///
+    /// [`to_array`]: #method.to_array
+    /// [`try_from_fen`]: #method.try_from_fen
+    pub fn from_array(
+        squares: [Option<(Piece, Color)>; 64],
+        side_to_move: Color,
+        castling_rights: CastlingRights,
+        en_passant_square: Option<Square>,
+    ) -> Result<Board, Error> {
+        let mut board = Board::new();
+
+        for (square, piece) in SQUARES.iter().zip(squares) {
+            if let Some((piece, color)) = piece {
+                board.set_piece(piece, color, *square);
             }
         }
 
-        board.side_to_move = match parts[1] {
-            "w" => Color::White,
-            "b" => Color::Black,
-            _ => panic!("Invalid active color in FEN"),
-        };
+        board.combined = board.occupancy(Color::White) | board.occupancy(Color::Black);
 
-        board.castling_rights = CastlingRights::from_fen(parts[2]);
+        if COLORS
+            .iter()
+            .any(|color| board.pieces_color(Piece::King, *color).count_ones() != 1)
+        {
+            return Err(Error::InvalidBoard);
+        }
 
-        board.side_to_move = !board.side_to_move;
+        if side_to_move == Color::Black {
+            board.flip_side_to_move();
+        }
 
-        board.parse_en_passant(parts[3]);
+        board.set_castling_rights(castling_rights);
 
-        board.side_to_move = !board.side_to_move;
+        if let Some(en_passant_square) = en_passant_square {
+            board.side_to_move = !board.side_to_move;
+
+            board.set_en_passant(en_passant_square);
+
+            board.side_to_move = !board.side_to_move;
+        }
+
+        board.update_check_and_pinned();
+
+        Ok(board)
+    }
+
+    /// Get the piece on every square of the [`Board`], indexed by [`Square::to_index`]. See
+    /// [`from_array`] for the inverse.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color, piece::Piece, square::Square};
+    ///
+    /// let squares = Board::default().to_array();
+    ///
+    /// assert_eq!(squares[Square::E1.to_index()], Some((Piece::King, Color::White)));
+    /// assert_eq!(squares[Square::E4.to_index()], None);
+    /// ```
+    ///
+    /// [`from_array`]: #method.from_array
+    pub fn to_array(&self) -> [Option<(Piece, Color)>; 64] {
+        let mut squares = [None; 64];
+
+        for (square, piece, color) in self.iter_pieces() {
+            squares[square.to_index()] = Some((piece, color));
+        }
+
+        squares
+    }
+
+    /// Recompute the check count for the side to move and the pinned pieces from scratch.
+    #[rustfmt::skip]
+    fn update_check_and_pinned(&mut self) {
+        let king_square = self.pieces_color(Piece::King, self.side_to_move).to_square();
+        self.check = self.get_attackers(king_square).count_ones() as u8;
+
+        self.pinned = EMPTY;
 
-        board.half_moves = parts[4].parse::<u8>().unwrap();
+        for color in COLORS {
+            let king_square = self.pieces_color(Piece::King, !color).to_square();
+            let attackers = self.occupancy(color) & ((get_bishop_rays(king_square) & (self.pieces(Piece::Bishop) | self.pieces(Piece::Queen)))
+                | (get_rook_rays(king_square) & (self.pieces(Piece::Rook) | self.pieces(Piece::Queen))));
 
-        board
+            for square in attackers {
+                let between = get_between(square, king_square) & self.combined();
+                if between.is_single() {
+                    self.pinned ^= between & self.occupancy(!color);
+                }
+            }
+        }
     }
 
-    fn parse_pieces(&mut self, piece_placement: &str) {
+    fn parse_pieces(&mut self, piece_placement: &str) -> Result<(), Error> {
         let mut rank = 7;
         let mut file = 0;
 
         for ch in piece_placement.chars() {
-            match ch {
+            let (piece, color) = match ch {
                 // Piece placement characters
-                'P' => self.place_piece(Piece::Pawn, Color::White, rank, file),
-                'N' => self.place_piece(Piece::Knight, Color::White, rank, file),
-                'B' => self.place_piece(Piece::Bishop, Color::White, rank, file),
-                'R' => self.place_piece(Piece::Rook, Color::White, rank, file),
-                'Q' => self.place_piece(Piece::Queen, Color::White, rank, file),
-                'K' => self.place_piece(Piece::King, Color::White, rank, file),
-                'p' => self.place_piece(Piece::Pawn, Color::Black, rank, file),
-                'n' => self.place_piece(Piece::Knight, Color::Black, rank, file),
-                'b' => self.place_piece(Piece::Bishop, Color::Black, rank, file),
-                'r' => self.place_piece(Piece::Rook, Color::Black, rank, file),
-                'q' => self.place_piece(Piece::Queen, Color::Black, rank, file),
-                'k' => self.place_piece(Piece::King, Color::Black, rank, file),
+                'P' => (Piece::Pawn, Color::White),
+                'N' => (Piece::Knight, Color::White),
+                'B' => (Piece::Bishop, Color::White),
+                'R' => (Piece::Rook, Color::White),
+                'Q' => (Piece::Queen, Color::White),
+                'K' => (Piece::King, Color::White),
+                'p' => (Piece::Pawn, Color::Black),
+                'n' => (Piece::Knight, Color::Black),
+                'b' => (Piece::Bishop, Color::Black),
+                'r' => (Piece::Rook, Color::Black),
+                'q' => (Piece::Queen, Color::Black),
+                'k' => (Piece::King, Color::Black),
 
                 // Empty squares
                 '1'..='8' => {
                     file += ch.to_digit(10).unwrap() as usize;
+                    if file > 8 {
+                        return Err(Error::InvalidFen);
+                    }
+                    continue;
                 }
 
                 // End of rank
                 '/' => {
+                    if rank == 0 || file != 8 {
+                        return Err(Error::InvalidFen);
+                    }
                     rank -= 1;
                     file = 0;
+                    continue;
                 }
 
-                _ => panic!("Invalid character in FEN piece placement: {}", ch),
-            }
+                _ => return Err(Error::InvalidFen),
+            };
 
-            // Move to the next file if piece was placed
-            if ch.is_alphabetic() {
-                file += 1;
+            if file >= 8 {
+                return Err(Error::InvalidFen);
             }
+
+            self.place_piece(piece, color, rank, file);
+            file += 1;
+        }
+
+        if rank != 0 || file != 8 {
+            return Err(Error::InvalidFen);
         }
+
+        Ok(())
     }
 
     fn place_piece(&mut self, piece: Piece, color: Color, rank: usize, file: usize) {
@@ -205,15 +518,71 @@ impl Board {
         self.set_piece(piece, color, square);
     }
 
-    fn parse_en_passant(&mut self, en_passant: &str) {
+    fn parse_en_passant(&mut self, en_passant: &str) -> Result<(), Error> {
         if en_passant != "-" {
-            let square = Square::from_str(en_passant).unwrap();
+            let square = Square::from_str(en_passant).map_err(|_| Error::InvalidFen)?;
             self.set_en_passant(square);
         }
+
+        Ok(())
     }
 
     /// Convert the current [`Board`] to a FEN.
     pub fn to_fen(&self) -> String {
+        let mut fen = self.placement_fen();
+
+        fen.push(' ');
+
+        match self.side_to_move {
+            Color::White => fen.push('w'),
+            Color::Black => fen.push('b'),
+        }
+
+        fen.push(' ');
+
+        fen.push_str(&self.castling_rights.to_fen());
+
+        fen.push(' ');
+
+        match self.en_passant_square() {
+            Some(en_passant_square) => fen.push_str(&en_passant_square.to_string()),
+            None => fen.push('-'),
+        }
+
+        fen.push(' ');
+
+        fen.push_str(&format!("{} {}", self.half_moves, self.full_moves));
+
+        fen
+    }
+
+    /// Split the current [`Board`] into the six fields of a FEN.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color};
+    ///
+    /// let mut fields = Board::default().fen_fields();
+    ///
+    /// assert_eq!(fields.placement, "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR");
+    ///
+    /// fields.side_to_move = Color::Black;
+    ///
+    /// assert_eq!(fields.to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1");
+    /// ```
+    pub fn fen_fields(&self) -> FenFields {
+        FenFields {
+            placement: self.placement_fen(),
+            side_to_move: self.side_to_move,
+            castling_rights: self.castling_rights,
+            en_passant_square: self.en_passant_square(),
+            half_moves: self.half_moves as u16,
+            full_moves: self.full_moves,
+        }
+    }
+
+    /// Get the piece placement field of the FEN for the current [`Board`].
+    fn placement_fen(&self) -> String {
         let mut fen = String::new();
 
         let mut empty = 0;
@@ -251,28 +620,6 @@ impl Board {
             }
         }
 
-        fen.push(' ');
-
-        match self.side_to_move {
-            Color::White => fen.push('w'),
-            Color::Black => fen.push('b'),
-        }
-
-        fen.push(' ');
-
-        fen.push_str(&self.castling_rights.to_fen());
-
-        fen.push(' ');
-
-        match self.en_passant_square() {
-            Some(en_passant_square) => fen.push_str(&en_passant_square.to_string()),
-            None => fen.push('-'),
-        }
-
-        fen.push(' ');
-
-        fen.push_str("0 1");
-
         fen
     }
 
@@ -291,6 +638,26 @@ impl Board {
         println!("{}", self);
     }
 
+    /// Check if two boards have the same position: the same pieces on the same squares, the same
+    /// side to move, castling rights and en passant square. The move counters are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 1");
+    ///
+    /// assert!(board.same_position(&Board::default()));
+    /// assert!(!board.same_position(&Board::kiwipete()));
+    /// ```
+    pub fn same_position(&self, other: &Board) -> bool {
+        self.pieces == other.pieces
+            && self.occupancy == other.occupancy
+            && self.side_to_move == other.side_to_move
+            && self.castling_rights == other.castling_rights
+            && self.en_passant_square == other.en_passant_square
+    }
+
     /// Get the combined bitboard of all pieces on the board.
     /// ```
     /// use chessframe::{bitboard::BitBoard, board::Board};
@@ -311,6 +678,8 @@ impl Board {
     }
 
     /// Get the pinned bitboard of all pieces that are pinned.
+    ///
+    /// If the pins are unknown, after [`make_move_fast`], they are computed on every call.
     /// ```
     /// use chessframe::{bitboard::BitBoard, board::Board};
     ///
@@ -318,39 +687,119 @@ impl Board {
     ///
     /// assert_eq!(board.pinned(), BitBoard(0));
     /// ```
+    ///
+    /// [`make_move_fast`]: #method.make_move_fast
     #[inline]
     pub fn pinned(&self) -> BitBoard {
-        self.pinned
+        if self.pinned == UNKNOWN_PINS {
+            self.compute_pinned()
+        } else {
+            self.pinned
+        }
     }
 
-    /// Get the occupancy bitboard for a particular color.
+    /// Compute the pinned pieces if they are unknown and store them in the [`Board`].
+    ///
+    /// # Example
     /// ```
-    /// use chessframe::{bitboard::BitBoard, board::Board, color::Color};
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
     ///
-    /// let board = Board::default();
+    /// let mut board = Board::default();
+    /// let mv = ChessMove::new(Square::E2, Square::E4);
     ///
-    /// assert_eq!(board.occupancy(Color::White), BitBoard(0x000000000000FFFF));
-    /// assert_eq!(board.occupancy(Color::Black), BitBoard(0xFFFF000000000000));
+    /// let _ = board.make_move_fast(mv);
+    /// board.update_pins();
+    ///
+    /// let made = Board::default().make_move_new(mv).unwrap();
+    ///
+    /// assert_eq!(board, made);
+    /// assert_eq!(board.pinned, made.pinned);
     /// ```
     #[inline]
-    pub fn occupancy(&self, color: Color) -> BitBoard {
-        unsafe { *self.occupancy.get_unchecked(color.to_index()) }
+    pub fn update_pins(&mut self) {
+        self.pinned = self.pinned();
     }
 
-    /// Get a mutable reference to the occupancy bitboard for a particular color.
-    #[inline]
-    pub fn occupancy_mut(&mut self, color: Color) -> &mut BitBoard {
-        unsafe { self.occupancy.get_unchecked_mut(color.to_index()) }
-    }
+    /// Compute the pieces standing alone between the king of the side to move and a sliding piece.
+    #[rustfmt::skip]
+    fn compute_pinned(&self) -> BitBoard {
+        let king_square = self.pieces_color(Piece::King, self.side_to_move).to_square();
+        let mut pinned = EMPTY;
 
-    /// Get the bitboard of a particular piece type.
-    /// ```
-    /// use chessframe::{bitboard::BitBoard, board::Board, piece::Piece};
-    ///
-    /// let board = Board::default();
-    ///
-    /// assert_eq!(board.pieces(Piece::Pawn), BitBoard(0x00FF00000000FF00));
-    /// ```
+        for color in COLORS {
+            let attackers = self.occupancy(color) & ((get_bishop_rays(king_square) & (self.pieces(Piece::Bishop) | self.pieces(Piece::Queen)))
+                | (get_rook_rays(king_square) & (self.pieces(Piece::Rook) | self.pieces(Piece::Queen))));
+
+            for square in attackers {
+                let between = get_between(square, king_square) & self.combined();
+                if between.is_single() {
+                    pinned ^= between & self.occupancy(!color);
+                }
+            }
+        }
+
+        pinned
+    }
+
+    /// Get the squares the piece on a given square may move to without exposing its king, the
+    /// line through the king and the piece if it is pinned, otherwise every square.
+    ///
+    /// Intersecting this with the moves of the piece gives the moves which are legal with respect
+    /// to pins.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/4r3/8/8/8/8/4R3/4K3 w - - 0 1");
+    ///
+    /// assert_eq!(
+    ///     board.pin_mask(Square::E2),
+    ///     BitBoard(0x1010101010101000)
+    /// );
+    /// assert_eq!(board.pin_mask(Square::E1), !EMPTY);
+    /// ```
+    #[inline]
+    pub fn pin_mask(&self, square: Square) -> BitBoard {
+        if !(self.pinned() & self.occupancy(self.side_to_move)).is_set(square) {
+            return !EMPTY;
+        }
+
+        let king_square = self
+            .pieces_color(Piece::King, self.side_to_move)
+            .to_square();
+
+        get_tangent(king_square, square) & !BitBoard::from_square(king_square)
+    }
+
+    /// Get the occupancy bitboard for a particular color.
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, board::Board, color::Color};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.occupancy(Color::White), BitBoard(0x000000000000FFFF));
+    /// assert_eq!(board.occupancy(Color::Black), BitBoard(0xFFFF000000000000));
+    /// ```
+    #[inline]
+    pub fn occupancy(&self, color: Color) -> BitBoard {
+        unsafe { *self.occupancy.get_unchecked(color.to_index()) }
+    }
+
+    /// Get a mutable reference to the occupancy bitboard for a particular color.
+    #[inline]
+    pub fn occupancy_mut(&mut self, color: Color) -> &mut BitBoard {
+        unsafe { self.occupancy.get_unchecked_mut(color.to_index()) }
+    }
+
+    /// Get the bitboard of a particular piece type.
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, board::Board, piece::Piece};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.pieces(Piece::Pawn), BitBoard(0x00FF00000000FF00));
+    /// ```
     #[inline]
     pub fn pieces(&self, piece: Piece) -> BitBoard {
         unsafe { *self.pieces.get_unchecked(piece.to_index()) }
@@ -368,7 +817,9 @@ impl Board {
         unsafe { self.pieces.get_unchecked_mut(piece.to_index()) }
     }
 
-    /// Get the zobrist hash of the board. This is different from the hash field in [`Board`] as it only contains the hash for the pieces.
+    /// Get the zobrist hash of the board, of the pieces, castling rights, en passant square and
+    /// side to move. This is the same as the hash field in [`Board`], which is updated
+    /// incrementally when making moves.
     /// # Examples
     ///
     /// Get the hash for the starting position
@@ -396,18 +847,173 @@ impl Board {
     #[inline]
     pub fn hash(&self) -> u64 {
         self.hash
-            ^ if let Some(en_passant_square) = self.en_passant_square {
-                Zobrist::get_en_passant(en_passant_square.file(), !self.side_to_move)
-            } else {
-                0
+    }
+
+    /// Get the key to compare positions with for repetitions, the zobrist hash of the
+    /// [`Board`], see [`hash`]. Two positions with the same key have the same pieces, side to
+    /// move, castling rights and en passant square, the en passant square only being set if it
+    /// can be captured.
+    ///
+    /// Positions can only repeat since the last irreversible move, a pawn move or a capture, so
+    /// callers keeping a history of keys should clear it, or stop looking back, whenever
+    /// `half_moves` is reset to `0`, like [`RepetitionTable::is_repetition`] does.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let mut board = Board::default();
+    /// let key = board.repetition_key();
+    ///
+    /// for (from, to) in [(Square::G1, Square::F3), (Square::G8, Square::F6), (Square::F3, Square::G1), (Square::F6, Square::G8)] {
+    ///     board.make_move(ChessMove::new(from, to)).unwrap();
+    /// }
+    ///
+    /// assert_eq!(board.repetition_key(), key);
+    /// ```
+    ///
+    /// [`hash`]: #method.hash
+    /// [`RepetitionTable::is_repetition`]: crate::repetitiontable::RepetitionTable::is_repetition
+    #[inline]
+    pub fn repetition_key(&self) -> u64 {
+        self.hash
+    }
+
+    /// Get the part of the zobrist hash for the castling rights of both colors.
+    #[inline]
+    fn castling_hash(castling_rights: CastlingRights) -> u64 {
+        Zobrist::get_castle(castling_rights, Color::White)
+            ^ Zobrist::get_castle(castling_rights, Color::Black)
+    }
+
+    /// Get the part of the zobrist hash for the castling rights, en passant square and side to
+    /// move, without the pieces.
+    #[inline]
+    fn state_hash(&self) -> u64 {
+        let mut hash = Board::castling_hash(self.castling_rights);
+
+        if let Some(en_passant_square) = self.en_passant_square {
+            hash ^= Zobrist::get_en_passant(en_passant_square.file(), !self.side_to_move);
+        }
+
+        if self.side_to_move == Color::Black {
+            hash ^= Zobrist::get_side_to_move();
+        }
+
+        hash
+    }
+
+    /// Compute the hash of the [`Board`] after making a [`ChessMove`], without making the move.
+    ///
+    /// # Parameters
+    /// - `mv`: A [`ChessMove`] representing the move to hash.
+    ///   The move must be either pseudo-legal or fully legal; for any other move the returned hash
+    ///   is meaningless.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let board = Board::default();
+    /// let mv = ChessMove::new(Square::E2, Square::E4);
+    ///
+    /// assert_eq!(board.hash_after(mv), board.make_move_new(mv).unwrap().hash());
+    /// ```
+    pub fn hash_after(&self, mv: ChessMove) -> u64 {
+        let (from, to) = mv.get_move();
+        let color = self.side_to_move;
+
+        let Some(piece) = self.get_piece(from) else {
+            return self.hash();
+        };
+
+        let mut hash = self.hash ^ self.state_hash() ^ Zobrist::get_piece(piece, from, color);
+
+        if let Some(captured) = self.get_piece(to) {
+            hash ^= Zobrist::get_piece(captured, to, !color);
+        }
+
+        let mut en_passant_square = None;
+
+        match piece {
+            Piece::Pawn => {
+                hash ^= Zobrist::get_piece(mv.promotion().unwrap_or(Piece::Pawn), to, color);
+
+                if Some(to) == self.en_passant_square() {
+                    hash ^= Zobrist::get_piece(Piece::Pawn, to.wrapping_backward(color), !color);
+                } else if from.rank() == color.to_second_rank()
+                    && to.rank() == color.to_fourth_rank()
+                    && get_adjacent_files(to.file())
+                        & get_rank(to.rank())
+                        & self.pieces_color(Piece::Pawn, !color)
+                        != EMPTY
+                {
+                    en_passant_square = Some(to.wrapping_backward(color));
+                }
             }
-            ^ Zobrist::get_castle(self.castling_rights, self.side_to_move)
-            ^ Zobrist::get_castle(self.castling_rights, !self.side_to_move)
-            ^ if self.side_to_move == Color::Black {
-                Zobrist::get_side_to_move()
-            } else {
-                0
+            Piece::King => {
+                hash ^= Zobrist::get_piece(Piece::King, to, color);
+
+                let move_bitboard = BitBoard::from_square(from) ^ BitBoard::from_square(to);
+                if move_bitboard.is_subset(get_castle_moves()) {
+                    let (start, end) = if to.file() == File::C {
+                        (File::A, File::D)
+                    } else {
+                        (File::H, File::F)
+                    };
+
+                    let start = Square::make_square(color.to_backrank(), start);
+                    let end = Square::make_square(color.to_backrank(), end);
+
+                    hash ^= Zobrist::get_piece(Piece::Rook, start, color)
+                        ^ Zobrist::get_piece(Piece::Rook, end, color);
+                }
             }
+            _ => hash ^= Zobrist::get_piece(piece, to, color),
+        }
+
+        let castling_rights = self
+            .castling_rights
+            .remove(CastlingRights::square_to_castle_rights(!color, to))
+            .remove(CastlingRights::square_to_castle_rights(color, from));
+
+        if let Some(en_passant_square) = en_passant_square {
+            hash ^= Zobrist::get_en_passant(en_passant_square.file(), color);
+        }
+
+        if color == Color::White {
+            hash ^= Zobrist::get_side_to_move();
+        }
+
+        hash ^ Board::castling_hash(castling_rights)
+    }
+
+    /// Check that the incrementally updated hash matches the zobrist hash of the [`Board`]
+    /// computed from scratch, from its pieces, castling rights, en passant square and side to
+    /// move.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let mut board = Board::default();
+    /// board.make_move(ChessMove::new(Square::E2, Square::E4)).unwrap();
+    ///
+    /// assert!(board.verify_hash());
+    /// ```
+    pub fn verify_hash(&self) -> bool {
+        self.hash == self.compute_hash()
+    }
+
+    /// Compute the zobrist hash of the [`Board`] from scratch.
+    fn compute_hash(&self) -> u64 {
+        let mut hash = self.state_hash();
+
+        for (square, piece, color) in self.iter_pieces() {
+            hash ^= Zobrist::get_piece(piece, square, color);
+        }
+
+        hash
     }
 
     /// Looks up the check field in the [`Board`] and checks if it's above `0`.
@@ -426,6 +1032,67 @@ impl Board {
         self.check > 0
     }
 
+    /// Return the number of pieces giving check to the side to move.
+    ///
+    /// `0` means the side to move is not in check, `1` is a single check and `2` is a double
+    /// check. On a double check only the king can move, so move generation skips straight to
+    /// king moves whenever this is `2` or more.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let fen = "4r2k/8/8/8/1b6/8/8/4K3 w - - 0 1";
+    /// let board = Board::from_fen(fen);
+    ///
+    /// assert_eq!(board.check_count(), 2);
+    /// ```
+    #[inline]
+    pub fn check_count(&self) -> u8 {
+        self.check
+    }
+
+    /// Check if the side to move is in double check, in which case only the king can move.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::from_fen("4r2k/8/8/8/1b6/8/8/4K3 w - - 0 1");
+    ///
+    /// assert!(board.is_double_check());
+    /// ```
+    #[inline]
+    pub fn is_double_check(&self) -> bool {
+        self.check >= 2
+    }
+
+    /// Get the squares between the king of the side to move and the piece checking it, where the
+    /// check can be blocked. Returns `EMPTY` if not in check, in double check, or if the checking
+    /// piece is adjacent or not a sliding piece.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, square::Square};
+    ///
+    /// let board = Board::from_fen("4r2k/8/8/8/8/8/8/4K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.block_squares(), BitBoard(0x0010101010101000));
+    /// assert_eq!(Board::default().block_squares(), EMPTY);
+    /// ```
+    pub fn block_squares(&self) -> BitBoard {
+        if self.check != 1 {
+            return EMPTY;
+        }
+
+        let king_square = self
+            .pieces_color(Piece::King, self.side_to_move)
+            .to_square();
+        let checker = self.get_attackers(king_square).to_square();
+
+        get_between(king_square, checker)
+    }
+
     /// Checks if the game is drawn based on the Fifty-move rule. Note does not check for
     /// checkmates.
     ///
@@ -475,10 +1142,55 @@ impl Board {
         self.en_passant_square
     }
 
+    /// Set the side to move, recomputing the check and pinned pieces. Changing the side to move
+    /// also removes the en passant square, since it is only valid for the side that could capture.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color};
+    ///
+    /// let mut board = Board::from_fen("4k3/8/8/8/4R3/8/8/4K3 w - - 0 1");
+    ///
+    /// assert!(!board.in_check());
+    ///
+    /// board.set_side_to_move(Color::Black);
+    ///
+    /// assert!(board.in_check());
+    /// ```
+    pub fn set_side_to_move(&mut self, color: Color) {
+        if self.side_to_move != color {
+            self.remove_en_passant();
+            self.flip_side_to_move();
+        }
+
+        self.update_check_and_pinned();
+    }
+
+    /// Pass the move to the other side, updating the hash.
+    #[inline]
+    fn flip_side_to_move(&mut self) {
+        self.side_to_move = !self.side_to_move;
+        self.hash ^= Zobrist::get_side_to_move();
+    }
+
+    /// Pass the move to the other side after a move, counting up the fullmove number after Black
+    /// has moved.
+    #[inline]
+    fn pass_turn(&mut self) {
+        if self.side_to_move == Color::Black {
+            self.full_moves += 1;
+        }
+        self.flip_side_to_move();
+    }
+
     fn remove_en_passant(&mut self) {
-        self.en_passant_square = None;
+        if let Some(en_passant_square) = self.en_passant_square.take() {
+            self.hash ^= Zobrist::get_en_passant(en_passant_square.file(), !self.side_to_move);
+        }
     }
 
+    /// Set the en passant square behind a pawn the side to move just pushed two squares, if an
+    /// opponent pawn can capture it. Must be called before passing the move to the other side.
     fn set_en_passant(&mut self, square: Square) {
         if get_adjacent_files(square.file())
             & get_rank(square.wrapping_backward(!self.side_to_move).rank())
@@ -486,9 +1198,18 @@ impl Board {
             != EMPTY
         {
             self.en_passant_square = Some(square);
+            self.hash ^= Zobrist::get_en_passant(square.file(), self.side_to_move);
         }
     }
 
+    /// Set the castling rights, updating the hash.
+    #[inline]
+    fn set_castling_rights(&mut self, castling_rights: CastlingRights) {
+        self.hash ^=
+            Board::castling_hash(self.castling_rights) ^ Board::castling_hash(castling_rights);
+        self.castling_rights = castling_rights;
+    }
+
     /// Remove the castling rights provided in the castling_rights parameter.
     ///
     /// # Example
@@ -503,11 +1224,11 @@ impl Board {
     /// assert_eq!(board.castling_rights, CastlingRights::from_fen("kq"));
     /// ```
     pub fn remove_castling_rights(&mut self, castling_rights: CastlingRights) {
-        self.castling_rights = self.castling_rights.remove(castling_rights);
+        self.set_castling_rights(self.castling_rights.remove(castling_rights));
     }
 
     pub fn add_castling_rights(&mut self, castling_rights: CastlingRights) {
-        self.castling_rights = self.castling_rights.add(castling_rights);
+        self.set_castling_rights(self.castling_rights.add(castling_rights));
     }
 
     pub(crate) fn xor(&mut self, bitboard: BitBoard, piece: Piece, color: Color) {
@@ -515,6 +1236,12 @@ impl Board {
         *self.occupancy_mut(color) ^= bitboard;
         *self.combined_mut() ^= bitboard;
         self.hash ^= Zobrist::get_piece(piece, bitboard.to_square(), color);
+
+        if self.occupancy(color).intersects(bitboard) {
+            self.material[color.to_index()] += piece.value();
+        } else {
+            self.material[color.to_index()] -= piece.value();
+        }
     }
 
     /// Check if one can castle to the given side.
@@ -621,56 +1348,489 @@ impl Board {
             return Err(Error::InvalidMove);
         }
 
-        let king_square = self
-            .pieces_color(Piece::King, self.side_to_move)
-            .to_square();
-
-        if self.pinned().is_set(from) && from != king_square {
-            let pinned_mask = get_tangent(king_square, from) & !BitBoard::from_square(king_square);
-            let enemy_pieces = pinned_mask & self.occupancy(!self.side_to_move);
-
-            if pinned_mask != EMPTY && enemy_pieces != EMPTY {
-                let allowed = piece_moves & pinned_mask;
-
-                if !allowed.is_set(to) {
-                    return Err(Error::CannotMovePinned);
-                }
-            }
+        if self.pin_mask(from).is_not_set(to) {
+            return Err(Error::CannotMovePinned);
         }
 
         Ok(piece)
     }
 
-    /// Make a [`ChessMove`] on a copy of the current [`Board`].
+    /// Find out why a [`ChessMove`] is illegal in the current position, returns `None` if the
+    /// move is legal.
     ///
-    /// # Parameters
-    /// - `mv`: A reference to a [`ChessMove`] representing the move to make.
-    ///   The move must be either pseudo-legal or fully legal; invalid or unchecked moves
-    ///   will result in undefined behavior.
+    /// # Example
+    /// ```
+    /// use chessframe::{board::{Board, IllegalReason}, chess_move::ChessMove, square::Square};
     ///
-    /// # Returns
-    /// - `Ok(Board)` if the move is successfully made.
-    /// - `Err(Error)` if the move is invalid, such as attempting to move a pinned piece.
+    /// let board = Board::default();
     ///
-    /// # Errors
-    /// - Returns an error if the resulting board state places or leaves the king in check.
-    /// - Errors when attempting to move a piece from an empty square or with other invalid conditions.
+    /// assert_eq!(board.why_illegal(ChessMove::new(Square::E2, Square::E4)), None);
+    /// assert_eq!(
+    ///     board.why_illegal(ChessMove::new(Square::E3, Square::E4)),
+    ///     Some(IllegalReason::NoPiece)
+    /// );
+    /// assert_eq!(
+    ///     board.why_illegal(ChessMove::new(Square::E7, Square::E5)),
+    ///     Some(IllegalReason::WrongColor)
+    /// );
+    /// assert_eq!(
+    ///     board.why_illegal(ChessMove::new(Square::E2, Square::E5)),
+    ///     Some(IllegalReason::NotPseudoLegal)
+    /// );
+    /// ```
+    pub fn why_illegal(&self, mv: ChessMove) -> Option<IllegalReason> {
+        let (from, to) = mv.get_move();
+
+        let Some(piece) = self.get_piece(from) else {
+            return Some(IllegalReason::NoPiece);
+        };
+
+        if self.occupancy(self.side_to_move).is_not_set(from) {
+            return Some(IllegalReason::WrongColor);
+        }
+
+        if !self
+            .generate_moves_vec(BitBoard::from_square(to))
+            .contains(&mv)
+        {
+            if piece == Piece::King && self.is_castle_attempt(from, to) {
+                return Some(IllegalReason::CastleThroughCheck);
+            }
+
+            return Some(IllegalReason::NotPseudoLegal);
+        }
+
+        if self.make_move_new(mv).is_err() {
+            return Some(IllegalReason::LeavesKingInCheck);
+        }
+
+        None
+    }
+
+    /// Convert a legal [`ChessMove`] to Standard Algebraic Notation, like `Nbd2`, `exd6`, `O-O`
+    /// or `a8=Q#`. The move is disambiguated against the other legal moves, and `+` or `#` is
+    /// added if it gives check or checkmate.
     ///
     /// # Example
     /// ```
     /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
     ///
     /// let board = Board::default();
-    /// let mv = ChessMove::new(Square::E2, Square::E4);
     ///
-    /// assert_eq!(board.make_move_new(mv), Ok(Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")));
+    /// assert_eq!(board.to_san(ChessMove::new(Square::E2, Square::E4)), "e4");
+    /// assert_eq!(board.to_san(ChessMove::new(Square::G1, Square::F3)), "Nf3");
     /// ```
+    pub fn to_san(&self, mv: ChessMove) -> String {
+        let (from, to) = mv.get_move();
+        let Some(piece) = self.get_piece(from) else {
+            return mv.to_string();
+        };
+
+        let mut san = String::new();
+
+        let from_name = from.to_string();
+        let (file, rank) = from_name.split_at(1);
+
+        let move_bitboard = BitBoard::from_square(from) ^ BitBoard::from_square(to);
+        if piece == Piece::King && move_bitboard.is_subset(get_castle_moves()) {
+            san.push_str(if to.file() == File::G { "O-O" } else { "O-O-O" });
+        } else {
+            let capture = self.occupancy(!self.side_to_move).is_set(to)
+                || (piece == Piece::Pawn && Some(to) == self.en_passant_square);
+
+            if piece == Piece::Pawn {
+                if capture {
+                    san.push_str(file);
+                }
+            } else {
+                san.push(piece.to_fen().to_ascii_uppercase());
+
+                let others: Vec<Square> = self
+                    .legal_moves()
+                    .into_iter()
+                    .filter(|other| {
+                        other.to == to
+                            && other.from != from
+                            && self.get_piece(other.from) == Some(piece)
+                    })
+                    .map(|other| other.from)
+                    .collect();
+
+                if !others.is_empty() {
+                    if others.iter().all(|other| other.file() != from.file()) {
+                        san.push_str(file);
+                    } else if others.iter().all(|other| other.rank() != from.rank()) {
+                        san.push_str(rank);
+                    } else {
+                        san.push_str(&from_name);
+                    }
+                }
+            }
+
+            if capture {
+                san.push('x');
+            }
+
+            san.push_str(&to.to_string());
+
+            if let Some(promotion) = mv.promotion() {
+                san.push('=');
+                san.push(promotion.to_fen().to_ascii_uppercase());
+            }
+        }
+
+        if let Ok(board) = self.make_move_new(mv)
+            && board.in_check()
+        {
+            let checkmate = board.count_legal_moves() == 0;
+            san.push(if checkmate { '#' } else { '+' });
+        }
+
+        san
+    }
+
+    /// Wrap a legal [`ChessMove`] in a [`SanMove`], displaying it in Standard Algebraic Notation
+    /// like [`to_san`]. The notation is only computed when the [`SanMove`] is formatted.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let board = Board::default();
+    /// let mv = ChessMove::new(Square::G1, Square::F3);
+    ///
+    /// assert_eq!(format!("1. {}", board.san_move(mv)), "1. Nf3");
+    /// ```
+    ///
+    /// [`to_san`]: #method.to_san
+    pub fn san_move(&self, mv: ChessMove) -> SanMove<'_> {
+        SanMove { board: self, mv }
+    }
+
+    /// Parse a move in Standard Algebraic Notation, like the ones produced by [`to_san`], into a
+    /// legal [`ChessMove`]. The move is read as an optional piece letter, an optional origin file,
+    /// rank or square, an optional `x`, the destination square and an optional promotion piece
+    /// with or without `=`, so over-disambiguated moves like `Nb1c3` are accepted too. The `+` and
+    /// `#` suffixes and annotations like `!?` are optional, and castling may also be written with
+    /// zeros, like `0-0`.
+    ///
+    /// # Errors
+    /// - Returns [`Error::InvalidMove`] if no legal move, or more than one, matches.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, error::Error, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.parse_san("Nf3"), Ok(ChessMove::new(Square::G1, Square::F3)));
+    /// assert_eq!(board.parse_san("Ng1f3"), Ok(ChessMove::new(Square::G1, Square::F3)));
+    /// assert_eq!(board.parse_san("e4!"), Ok(ChessMove::new(Square::E2, Square::E4)));
+    /// assert_eq!(board.parse_san("e5"), Err(Error::InvalidMove));
+    /// ```
+    ///
+    /// [`to_san`]: #method.to_san
+    pub fn parse_san(&self, san: &str) -> Result<ChessMove, Error> {
+        let san = san.trim().trim_end_matches(['+', '#', '!', '?']);
+        if !san.is_ascii() {
+            return Err(Error::InvalidMove);
+        }
+
+        let is_castle = |mv: &ChessMove| {
+            self.get_piece(mv.from) == Some(Piece::King)
+                && (BitBoard::from_square(mv.from) ^ BitBoard::from_square(mv.to))
+                    .is_subset(get_castle_moves())
+        };
+
+        let castle_file = match san {
+            "O-O" | "0-0" => Some(File::G),
+            "O-O-O" | "0-0-0" => Some(File::C),
+            _ => None,
+        };
+
+        if let Some(file) = castle_file {
+            return self
+                .legal_moves()
+                .into_iter()
+                .find(|mv| is_castle(mv) && mv.to.file() == file)
+                .ok_or(Error::InvalidMove);
+        }
+
+        let (piece, rest) = match san.as_bytes().first() {
+            Some(b'N' | b'B' | b'R' | b'Q' | b'K') => (Piece::from_str(&san[..1])?, &san[1..]),
+            _ => (Piece::Pawn, san),
+        };
+
+        let (rest, promotion) = match rest.as_bytes().last() {
+            Some(b'N' | b'B' | b'R' | b'Q') => {
+                let (rest, promotion) = rest.split_at(rest.len() - 1);
+                (
+                    rest.strip_suffix('=').unwrap_or(rest),
+                    Some(Piece::from_str(promotion)?),
+                )
+            }
+            _ => (rest, None),
+        };
+
+        if rest.len() < 2 {
+            return Err(Error::InvalidMove);
+        }
+
+        let (from, to) = rest.split_at(rest.len() - 2);
+        let to = Square::from_str(to).map_err(|_| Error::InvalidMove)?;
+        let from = from.strip_suffix('x').unwrap_or(from);
+
+        let (from_file, from_rank) = match from.len() {
+            0 => (None, None),
+            1 => match File::from_str(from) {
+                Ok(file) => (Some(file), None),
+                Err(_) => (None, Some(Rank::from_str(from).map_err(|_| Error::InvalidMove)?)),
+            },
+            2 => {
+                let from = Square::from_str(from).map_err(|_| Error::InvalidMove)?;
+                (Some(from.file()), Some(from.rank()))
+            }
+            _ => return Err(Error::InvalidMove),
+        };
+
+        let mut candidates = self.legal_moves().into_iter().filter(|mv| {
+            mv.to == to
+                && mv.promotion() == promotion
+                && self.get_piece(mv.from) == Some(piece)
+                && !is_castle(mv)
+                && from_file.is_none_or(|file| mv.from.file() == file)
+                && from_rank.is_none_or(|rank| mv.from.rank() == rank)
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(mv), None) => Ok(mv),
+            _ => Err(Error::InvalidMove),
+        }
+    }
+
+    /// Play a line of moves in Standard Algebraic Notation separated by whitespace, like
+    /// `"e4 e5 Nf3"`, see [`parse_san`]. Move numbers like `1.` are skipped. If a move can't be
+    /// played the [`Board`] is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let mut board = Board::default();
+    /// board.play_san_line("1. e4 e5 2. Nf3").unwrap();
+    ///
+    /// assert_eq!(
+    ///     board.to_fen(),
+    ///     "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+    /// );
+    /// ```
+    ///
+    /// [`parse_san`]: #method.parse_san
+    pub fn play_san_line(&mut self, sans: &str) -> Result<(), Error> {
+        let mut board = *self;
+
+        for san in sans.split_whitespace() {
+            if san.ends_with('.') && san.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+
+            let mv = board.parse_san(san)?;
+            board.make_move(mv)?;
+        }
+
+        *self = board;
+
+        Ok(())
+    }
+
+    /// Parse a legal [`ChessMove`] written in either UCI notation, like `g1f3`, see
+    /// [`infer_move`], or Standard Algebraic Notation, like `Nf3`, see [`parse_san`]. UCI
+    /// notation is tried first, if the move could be one, that is four characters or five ending
+    /// in a lowercase promotion piece.
+    ///
+    /// # Errors
+    /// - Returns [`Error::InvalidMove`] if no legal move matches.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, error::Error, square::Square};
+    ///
+    /// let mut board = Board::default();
+    ///
+    /// assert_eq!(board.parse_move("e2e4"), Ok(ChessMove::new(Square::E2, Square::E4)));
+    /// assert_eq!(board.parse_move("e4"), Ok(ChessMove::new(Square::E2, Square::E4)));
+    /// assert_eq!(board.parse_move("e2e5"), Err(Error::InvalidMove));
+    /// assert_eq!(board.parse_move("e2e4x"), Err(Error::InvalidMove));
+    /// ```
+    ///
+    /// [`infer_move`]: #method.infer_move
+    /// [`parse_san`]: #method.parse_san
+    pub fn parse_move(&mut self, mv: &str) -> Result<ChessMove, Error> {
+        let mv = mv.trim();
+
+        let uci_like = match mv.len() {
+            4 => mv.is_ascii(),
+            5 => mv.is_ascii() && mv.ends_with(['n', 'b', 'r', 'q']),
+            _ => false,
+        };
+
+        if uci_like
+            && let Ok(uci) = self.infer_move(mv)
+            && self.make_move_new(uci).is_ok()
+        {
+            return Ok(uci);
+        }
+
+        self.parse_san(mv)
+    }
+
+    /// Check if a [`ChessMove`] is tactical, that is a capture, a promotion or a move that gives
+    /// check.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, piece::Piece, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/P7/8/8/2q5/8/8/4K1N1 w - - 0 1");
+    ///
+    /// assert!(!board.is_tactical(ChessMove::new(Square::G1, Square::F3)));
+    /// assert!(board.is_tactical(ChessMove::new_promotion(Square::A7, Square::A8, Piece::Queen)));
+    /// ```
+    pub fn is_tactical(&self, mv: ChessMove) -> bool {
+        let (from, to) = mv.get_move();
+
+        let capture = self.occupancy(!self.side_to_move).is_set(to)
+            || (Some(to) == self.en_passant_square && self.get_piece(from) == Some(Piece::Pawn));
+
+        capture
+            || mv.promotion().is_some()
+            || self.make_move_new(mv).is_ok_and(|board| board.in_check())
+    }
+
+    /// Check if a [`ChessMove`] is pseudo-legal, that is if it would be generated by
+    /// [`generate_moves_vec`], without generating all the moves. This is useful to guard against
+    /// corrupt moves, like a move from a transposition table after a hash collision.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert!(board.is_pseudo_legal(ChessMove::new(Square::G1, Square::F3)));
+    /// assert!(!board.is_pseudo_legal(ChessMove::new(Square::G1, Square::G3)));
+    /// assert!(!board.is_pseudo_legal(ChessMove::new(Square::G8, Square::F6)));
+    /// ```
+    ///
+    /// [`generate_moves_vec`]: #method.generate_moves_vec
+    pub fn is_pseudo_legal(&self, mv: ChessMove) -> bool {
+        let (from, to) = mv.get_move();
+        let color = self.side_to_move;
+
+        if self.occupancy(color).is_not_set(from) || self.occupancy(color).is_set(to) {
+            return false;
+        }
+
+        let Some(piece) = self.get_piece(from) else {
+            return false;
+        };
+
+        if self.is_double_check() && piece != Piece::King {
+            return false;
+        }
+
+        let blockers = self.combined();
+
+        let moves = match piece {
+            Piece::Pawn => {
+                if self.is_promotion(to)
+                    != matches!(
+                        mv.promotion(),
+                        Some(Piece::Knight | Piece::Bishop | Piece::Rook | Piece::Queen)
+                    )
+                {
+                    return false;
+                }
+
+                let mut targets = self.occupancy(!color);
+                if let Some(en_passant) = self.en_passant_square {
+                    targets |= BitBoard::from_square(en_passant);
+                }
+
+                let pushes = if blockers.is_not_set(from.wrapping_forward(color)) {
+                    get_pawn_moves(from, color) & !blockers
+                } else {
+                    EMPTY
+                };
+
+                (get_pawn_attacks(from, color) & targets) | pushes
+            }
+            _ if mv.promotion().is_some() => return false,
+            Piece::Knight => get_knight_moves(from),
+            Piece::Bishop => get_bishop_moves(from, blockers),
+            Piece::Rook => get_rook_moves(from, blockers),
+            Piece::Queen => get_bishop_moves(from, blockers) | get_rook_moves(from, blockers),
+            Piece::King => get_king_moves(from) | self.generate_castling_moves(),
+        };
+
+        moves.is_set(to)
+    }
+
+    /// Check if moving the king from `from` to `to` is a castle with the right to castle and
+    /// nothing in the way, ignoring any attacked squares.
+    fn is_castle_attempt(&self, from: Square, to: Square) -> bool {
+        let backrank = self.side_to_move.to_backrank();
+        let move_bitboard = BitBoard::from_square(from) ^ BitBoard::from_square(to);
+
+        if from.rank() != backrank
+            || to.rank() != backrank
+            || !move_bitboard.is_subset(get_castle_moves())
+        {
+            return false;
+        }
+
+        let kingside = to.file() == File::G;
+        let rook = Square::make_square(backrank, if kingside { File::H } else { File::A });
+
+        self.castling_rights.can_castle(self.side_to_move, kingside)
+            && (get_between(from, rook) & self.combined()) == EMPTY
+    }
+
+    /// Make a [`ChessMove`] on a copy of the current [`Board`].
+    ///
+    /// # Parameters
+    /// - `mv`: A reference to a [`ChessMove`] representing the move to make.
+    ///   The move must be either pseudo-legal or fully legal; invalid or unchecked moves
+    ///   will result in undefined behavior.
+    ///
+    /// # Returns
+    /// - `Ok(Board)` if the move is successfully made.
+    /// - `Err(Error)` if the move is invalid, such as attempting to move a pinned piece.
+    ///
+    /// # Errors
+    /// - Returns an error if the resulting board state places or leaves the king in check.
+    /// - Errors when attempting to move a piece from an empty square or with other invalid conditions.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let board = Board::default();
+    /// let mv = ChessMove::new(Square::E2, Square::E4);
+    ///
+    /// let made = board.make_move_new(mv).unwrap();
+    /// let expected = Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1");
+    ///
+    /// assert_eq!(made, expected);
+    /// assert_eq!(made.pinned(), expected.pinned());
+    /// assert_eq!(made.in_check(), expected.in_check());
+    /// ```
+    ///
+    /// # Notes
+    /// This method assumes all moves are pre-validated (pseudo-legal or legal) or generated by [`generate_moves_vec`].
+    /// It does not perform a legality check before execution but will enforce
+    /// certain rules (e.g., pinned pieces cannot move) during processing.
     ///
-    /// # Notes
-    /// This method assumes all moves are pre-validated (pseudo-legal or legal) or generated by [`generate_moves_vec`].
-    /// It does not perform a legality check before execution but will enforce
-    /// certain rules (e.g., pinned pieces cannot move) during processing.
-    ///
     /// [`generate_moves_vec`]: #method.generate_moves_vec
     pub fn make_move_new(&self, mv: ChessMove) -> Result<Board, Error> {
         let mut board = *self;
@@ -680,6 +1840,33 @@ impl Board {
         Ok(board)
     }
 
+    /// Make a [`ChessMove`] on a copy of the current [`Board`], returning `None` if the move is
+    /// illegal. This is [`make_move_new`] with the error discarded, which reads better when
+    /// chaining, like in `filter_map`.
+    ///
+    /// The same pre-validation requirements as [`make_move_new`] apply.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::EMPTY, board::Board};
+    ///
+    /// // The knight on e2 is pinned, only the king moves are legal.
+    /// let board = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1");
+    ///
+    /// let boards: Vec<Board> = board
+    ///     .generate_moves_vec(!EMPTY)
+    ///     .iter()
+    ///     .filter_map(|mv| board.with_move(*mv))
+    ///     .collect();
+    ///
+    /// assert_eq!(boards.len(), 4);
+    /// ```
+    ///
+    /// [`make_move_new`]: #method.make_move_new
+    pub fn with_move(&self, mv: ChessMove) -> Option<Board> {
+        self.make_move_new(mv).ok()
+    }
+
     /// Make a [`ChessMove`] on the current [`Board`].
     ///
     /// # Parameters
@@ -711,10 +1898,60 @@ impl Board {
     /// certain rules (e.g., pinned pieces cannot move) during processing.
     ///
     /// [`generate_moves_vec`]: #method.generate_moves_vec
-    #[rustfmt::skip]
+    #[inline]
     pub fn make_move(&mut self, mv: ChessMove) -> Result<(), Error> {
+        self.make_move_inner::<false>(mv)
+    }
+
+    /// Make a [`ChessMove`] on the current [`Board`], without scanning for pinned pieces.
+    ///
+    /// This behaves like [`make_move`], except that the pinned pieces aren't computed after the
+    /// move. Instead they are marked as unknown and computed on demand by [`pinned`], and every
+    /// following move is verified to not leave the king in check. The check count is still
+    /// computed eagerly, so [`in_check`] stays cheap.
+    ///
+    /// This pays off when many of the resulting positions are never expanded, like at the leaves
+    /// of a search or perft. When [`pinned`] or [`validate_move`] is called on most positions it is
+    /// slower than [`make_move`], since the pins are recomputed on every call; use
+    /// [`update_pins`] to store them once.
+    ///
+    /// A [`Board`] with unknown pins still compares equal to the same position made with
+    /// [`make_move`], since only the position is compared.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let mut board = Board::default();
+    /// let mv = ChessMove::new(Square::E2, Square::E4);
+    ///
+    /// assert_eq!(board.make_move_fast(mv), Ok(()));
+    /// assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1");
+    /// ```
+    ///
+    /// [`make_move`]: #method.make_move
+    /// [`pinned`]: #method.pinned
+    /// [`in_check`]: #method.in_check
+    /// [`validate_move`]: #method.validate_move
+    /// [`update_pins`]: #method.update_pins
+    #[inline]
+    pub fn make_move_fast(&mut self, mv: ChessMove) -> Result<(), Error> {
+        self.make_move_inner::<true>(mv)
+    }
+
+    #[rustfmt::skip]
+    #[inline(always)]
+    fn make_move_inner<const LAZY_PINS: bool>(&mut self, mv: ChessMove) -> Result<(), Error> {
         let (from, to) = mv.get_move();
 
+        let piece = self.get_piece(from).ok_or(Error::NoPieceOnSquare)?;
+
+        let en_passant_square = self.en_passant_square();
+        let en_passant = piece == Piece::Pawn && Some(to) == en_passant_square;
+        if en_passant && self.en_passant_exposes_king(from, to) {
+            return Err(Error::CannotMovePinned);
+        }
+
         let in_check = self.check > 0;
         self.check = 0;
 
@@ -724,9 +1961,6 @@ impl Board {
         let to_bitboard = BitBoard::from_square(to);
         let move_bitboard = from_bitboard ^ to_bitboard;
 
-        let piece = self.get_piece(from).ok_or(Error::NoPieceOnSquare)?;
-
-        let en_passant_square = self.en_passant_square();
         self.remove_en_passant();
 
         if let Some(captured) = self.get_piece(to) {
@@ -748,7 +1982,7 @@ impl Board {
 
         let king_square = self.pieces_color(Piece::King, !self.side_to_move).to_square();
 
-        let castle = piece == Piece::King && (move_bitboard & get_castle_moves()) == move_bitboard;
+        let castle = piece == Piece::King && move_bitboard.is_subset(get_castle_moves());
 
         const CASTLE_ROOK_START: [File; 8] = [
             File::A,
@@ -788,7 +2022,7 @@ impl Board {
             {
                 self.set_en_passant(to.wrapping_backward(self.side_to_move));
                 self.check = (get_pawn_attacks(king_square, !self.side_to_move) & to_bitboard != EMPTY) as u8;
-            } else if Some(to) == en_passant_square {
+            } else if en_passant {
                 let side_to_move = self.side_to_move;
                 self.xor(
                     BitBoard::from_square(to.wrapping_backward(side_to_move)),
@@ -823,6 +2057,14 @@ impl Board {
             return Err(Error::CannotMovePinned);
         }
 
+        if LAZY_PINS {
+            self.pass_turn();
+            self.pinned = UNKNOWN_PINS;
+            self.check = self.get_attackers(king_square).count_ones() as u8;
+
+            return Ok(());
+        }
+
         self.pinned = EMPTY;
 
         for color in COLORS {
@@ -831,7 +2073,7 @@ impl Board {
 
             for square in attackers {
                 let between = get_between(square, king_square) & self.combined();
-                if between.count_ones() == 1 {
+                if between.is_single() {
                     self.pinned ^= between & self.occupancy(!color);
                 } else if between == EMPTY && self.side_to_move == color {
                     self.check += 1;
@@ -839,11 +2081,33 @@ impl Board {
             }
         }
 
-        self.side_to_move = !self.side_to_move;
+        self.pass_turn();
 
         Ok(())
     }
 
+    /// Check if capturing en passant from `from` to `to` exposes the king of the side to move,
+    /// with both the capturing and the captured pawn removed. Neither pawn has to be pinned on its
+    /// own for this to happen, both along the rank and along a diagonal through the captured pawn.
+    fn en_passant_exposes_king(&self, from: Square, to: Square) -> bool {
+        let king_square = self
+            .pieces_color(Piece::King, self.side_to_move)
+            .to_square();
+        let captured = to.wrapping_backward(self.side_to_move);
+
+        let occupied =
+            (self.combined() ^ BitBoard::from_square(from) ^ BitBoard::from_square(captured))
+                | BitBoard::from_square(to);
+
+        let opponent = self.occupancy(!self.side_to_move);
+        let bishops = (self.pieces(Piece::Bishop) | self.pieces(Piece::Queen)) & opponent;
+        let rooks = (self.pieces(Piece::Rook) | self.pieces(Piece::Queen)) & opponent;
+
+        (get_bishop_moves(king_square, occupied) & bishops)
+            | (get_rook_moves(king_square, occupied) & rooks)
+            != EMPTY
+    }
+
     pub fn make_null_move_new(&self) -> Result<Board, Error> {
         let mut board = *self;
 
@@ -881,7 +2145,7 @@ impl Board {
 
             for square in attackers {
                 let between = get_between(square, king_square) & self.combined();
-                if between.count_ones() == 1 {
+                if between.is_single() {
                     self.pinned ^= between & self.occupancy(!color);
                 } else if between == EMPTY && self.side_to_move == color {
                     self.check += 1;
@@ -889,7 +2153,7 @@ impl Board {
             }
         }
 
-        self.side_to_move = !self.side_to_move;
+        self.pass_turn();
 
         Ok(())
     }
@@ -902,6 +2166,7 @@ impl Board {
             pinned: self.pinned,
             check: self.check,
             half_moves: self.half_moves,
+            full_moves: self.full_moves,
         }
     }
 
@@ -920,13 +2185,14 @@ impl Board {
 
         let piece = self.get_piece(to).ok_or(Error::NoPieceOnSquare)?;
 
-        self.en_passant_square = unmake_data.en_passant_square;
-        self.castling_rights = unmake_data.castling_rights;
+        self.remove_en_passant();
+        self.set_castling_rights(unmake_data.castling_rights);
 
         self.pinned = unmake_data.pinned;
         self.check = unmake_data.check;
 
         self.half_moves = unmake_data.half_moves;
+        self.full_moves = unmake_data.full_moves;
 
         const CASTLE_ROOK_START: [File; 8] = [
             File::A,
@@ -949,7 +2215,12 @@ impl Board {
             File::F,
         ];
 
-        self.side_to_move = !self.side_to_move;
+        self.flip_side_to_move();
+
+        if let Some(en_passant_square) = unmake_data.en_passant_square {
+            self.en_passant_square = Some(en_passant_square);
+            self.hash ^= Zobrist::get_en_passant(en_passant_square.file(), !self.side_to_move);
+        }
 
         self.xor(to_bitboard, piece, self.side_to_move);
         self.xor(from_bitboard, piece, self.side_to_move);
@@ -1099,10 +2370,20 @@ impl Board {
     pub fn make_move_metadata(&mut self, mv: ChessMove) -> Result<MoveMetaData, Error> {
         let (from, to) = mv.get_move();
 
-        let unmake_data = self.unmake_data();
-
-        let in_check = self.check > 0;
-        self.check = 0;
+        let piece = self.get_piece(from).ok_or(Error::NoPieceOnSquare)?;
+
+        let en_passant_square = self.en_passant_square();
+        if piece == Piece::Pawn
+            && Some(to) == en_passant_square
+            && self.en_passant_exposes_king(from, to)
+        {
+            return Err(Error::CannotMovePinned);
+        }
+
+        let unmake_data = self.unmake_data();
+
+        let in_check = self.check > 0;
+        self.check = 0;
 
         self.half_moves += 1;
 
@@ -1110,10 +2391,8 @@ impl Board {
         let to_bitboard = BitBoard::from_square(to);
         let move_bitboard = from_bitboard ^ to_bitboard;
 
-        let piece = self.get_piece(from).ok_or(Error::NoPieceOnSquare)?;
         let mut en_passant = false;
 
-        let en_passant_square = self.en_passant_square();
         self.remove_en_passant();
 
         let captured = self.get_piece(to);
@@ -1136,7 +2415,7 @@ impl Board {
 
         let king_square = self.pieces_color(Piece::King, !self.side_to_move).to_square();
 
-        let castle = piece == Piece::King && (move_bitboard & get_castle_moves()) == move_bitboard;
+        let castle = piece == Piece::King && move_bitboard.is_subset(get_castle_moves());
 
         const CASTLE_ROOK_START: [File; 8] = [
             File::A,
@@ -1211,12 +2490,16 @@ impl Board {
                 )
                 .is_not_zero()
         {
-            self.side_to_move = !self.side_to_move;
+            self.flip_side_to_move();
             self.unmake_move(mv, metadata, unmake_data)?;
 
             return Err(Error::CannotMovePinned);
         }
 
+        if self.pinned == UNKNOWN_PINS {
+            self.pinned = EMPTY;
+        }
+
         self.pinned &= !self.occupancy(!self.side_to_move);
 
         let attackers = self.occupancy(self.side_to_move) & ((get_bishop_rays(king_square) & (self.pieces(Piece::Bishop) | self.pieces(Piece::Queen)))
@@ -1224,18 +2507,46 @@ impl Board {
 
         for square in attackers {
             let between = get_between(square, king_square) & self.combined();
-            if between.count_ones() == 1 {
+            if between.is_single() {
                 self.pinned ^= between & self.occupancy(!self.side_to_move);
             } else if between == EMPTY {
                 self.check += 1;
             }
         }
 
-        self.side_to_move = !self.side_to_move;
+        self.pass_turn();
 
         Ok(metadata)
     }
 
+    /// Make a [`ChessMove`] on the current [`Board`], returning the move in Standard Algebraic
+    /// Notation, see [`to_san`], together with the [`MoveMetaData`].
+    ///
+    /// The SAN is computed on the [`Board`] before the move is made, and the [`Board`] is left
+    /// unchanged if the move fails.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::{ChessMove, MoveMetaData}, piece::Piece, square::Square};
+    ///
+    /// let mut board = Board::from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2");
+    ///
+    /// assert_eq!(
+    ///     board.make_move_logged(ChessMove::new(Square::E4, Square::D5)),
+    ///     Ok(("exd5".to_string(), MoveMetaData::Capture(Piece::Pawn))),
+    /// );
+    /// ```
+    ///
+    /// [`to_san`]: #method.to_san
+    pub fn make_move_logged(&mut self, mv: ChessMove) -> Result<(String, MoveMetaData), Error> {
+        let san = self.to_san(mv);
+        let (board, metadata) = self.make_move_new_metadata(mv)?;
+
+        *self = board;
+
+        Ok((san, metadata))
+    }
+
     /// Get the piece at a given square.
     ///
     /// # Example
@@ -1270,6 +2581,48 @@ impl Board {
         }
     }
 
+    /// Get the color of the piece at a given square.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.color_on(Square::E1), Some(Color::White));
+    /// assert_eq!(board.color_on(Square::E8), Some(Color::Black));
+    /// assert_eq!(board.color_on(Square::E4), None);
+    /// ```
+    pub fn color_on(&self, square: Square) -> Option<Color> {
+        if self.occupancy(Color::White).is_set(square) {
+            Some(Color::White)
+        } else if self.occupancy(Color::Black).is_set(square) {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over every piece on the board, yielding its [`Square`], [`Piece`] and [`Color`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color, piece::Piece, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.iter_pieces().count(), 32);
+    /// assert!(board.iter_pieces().any(|piece| piece == (Square::E1, Piece::King, Color::White)));
+    /// ```
+    pub fn iter_pieces(&self) -> impl Iterator<Item = (Square, Piece, Color)> + '_ {
+        PIECES.iter().flat_map(move |&piece| {
+            COLORS.iter().flat_map(move |&color| {
+                self.pieces_color(piece, color)
+                    .map(move |square| (square, piece, color))
+            })
+        })
+    }
+
     /// Set the piece at a given square (used during board construction).
     fn set_piece(&mut self, piece: Piece, color: Color, square: Square) {
         self.xor(BitBoard::from_square(square), piece, color);
@@ -1297,6 +2650,9 @@ impl Board {
 
     /// Generate a vector of psudo-legal [`ChessMove`]'s.
     ///
+    /// Only moves landing on a square set in `mask` are generated, this applies uniformly to
+    /// every piece, including quiet pawn pushes and en passant. Use `!EMPTY` to generate all moves.
+    ///
     /// # Example
     /// ```
     /// use chessframe::{board::Board, bitboard::EMPTY};
@@ -1306,15 +2662,56 @@ impl Board {
     ///
     /// assert_eq!(board.generate_moves_vec(!EMPTY).len(), 20);
     /// ```
-    #[rustfmt::skip]
     pub fn generate_moves_vec(&self, mask: BitBoard) -> Vec<ChessMove> {
+        self.generate_moves_with(mask, GenOptions::default())
+    }
+
+    /// Generate a vector of psudo-legal [`ChessMove`]'s, restricted by the given [`GenOptions`].
+    ///
+    /// Only moves landing on a square set in `mask` are generated, see [`generate_moves_vec`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::EMPTY, board::{Board, GenOptions}, piece::Piece};
+    ///
+    /// let board = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1");
+    /// let options = GenOptions {
+    ///     underpromotions: false,
+    ///     ..GenOptions::default()
+    /// };
+    ///
+    /// let moves = board.generate_moves_with(!EMPTY, options);
+    /// let promotions: Vec<_> = moves.iter().filter_map(|mv| mv.promotion()).collect();
+    ///
+    /// assert_eq!(promotions, vec![Piece::Queen]);
+    /// assert_eq!(board.generate_moves_vec(!EMPTY).len(), moves.len() + 3);
+    /// ```
+    ///
+    /// [`generate_moves_vec`]: #method.generate_moves_vec
+    #[rustfmt::skip]
+    pub fn generate_moves_with(&self, mask: BitBoard, options: GenOptions) -> Vec<ChessMove> {
         let mut moves: Vec<ChessMove> = Vec::with_capacity(96);
 
         let allied_pieces = self.occupancy(self.side_to_move);
         let opponent_occupancy = self.occupancy(!self.side_to_move);
         let blockers = self.combined();
 
-        if self.check < 2 {
+        let en_passant_mask = mask;
+        let mut mask = mask;
+        if !options.captures {
+            mask &= !opponent_occupancy;
+        }
+        if !options.quiets {
+            mask &= opponent_occupancy;
+        }
+
+        let promotions: &[Piece] = if options.underpromotions {
+            &[Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen]
+        } else {
+            &[Piece::Queen]
+        };
+
+        if !self.is_double_check() {
             for piece in [
                 Piece::Knight,
                 Piece::Bishop,
@@ -1338,27 +2735,21 @@ impl Board {
                 }
             }
 
-            for src in self
-                .pieces_color(Piece::Pawn, self.side_to_move)
-                .into_iter()
-            {
-                let pawn_moves = {
-                    if (BitBoard::from_square(src.wrapping_forward(self.side_to_move))
-                        & !self.combined())
-                        != EMPTY
-                    {
-                        get_pawn_moves(src, self.side_to_move) & !self.combined() & mask
-                    } else {
-                        EMPTY
-                    }
-                } | (get_pawn_attacks(src, self.side_to_move) & opponent_occupancy) & mask;
+            for src in self.movable_pawns() {
+                let pawn_moves = if (BitBoard::from_square(src.wrapping_forward(self.side_to_move))
+                    & !self.combined())
+                    != EMPTY
+                {
+                    get_pawn_moves(src, self.side_to_move) & !self.combined()
+                } else {
+                    EMPTY
+                } | (get_pawn_attacks(src, self.side_to_move) & opponent_occupancy);
 
-                for dest in pawn_moves {
+                for dest in pawn_moves & mask {
                     if self.is_promotion(dest) {
-                        moves.push(ChessMove::new_promotion(src, dest, Piece::Knight));
-                        moves.push(ChessMove::new_promotion(src, dest, Piece::Bishop));
-                        moves.push(ChessMove::new_promotion(src, dest, Piece::Rook));
-                        moves.push(ChessMove::new_promotion(src, dest, Piece::Queen));
+                        for promotion in promotions {
+                            moves.push(ChessMove::new_promotion(src, dest, *promotion));
+                        }
                     } else {
                         moves.push(ChessMove::new(src, dest));
                     }
@@ -1366,7 +2757,8 @@ impl Board {
             }
 
             if let Some(en_passant) = self.en_passant_square
-                && mask & BitBoard::from_square(en_passant) != EMPTY {
+                && options.captures
+                && en_passant_mask & BitBoard::from_square(en_passant) != EMPTY {
                     for src in get_pawn_attacks(en_passant, !self.side_to_move)
                         & self.pieces_color(Piece::Pawn, self.side_to_move)
                     {
@@ -1389,8 +2781,236 @@ impl Board {
         moves
     }
 
+    /// Generate a vector of legal [`ChessMove`]'s, the pseudo-legal moves from
+    /// [`generate_moves_vec`] that don't leave the king in check.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// // The en passant capture would expose the white king to the queen on h5.
+    /// let board = Board::from_fen("8/8/8/K2Pp2q/8/8/8/7k w - e6 0 1");
+    ///
+    /// assert_eq!(board.legal_moves().len(), 6);
+    /// ```
+    ///
+    /// [`generate_moves_vec`]: #method.generate_moves_vec
+    pub fn legal_moves(&self) -> Vec<ChessMove> {
+        self.generate_moves_vec(!EMPTY)
+            .into_iter()
+            .filter(|mv| self.make_move_new(*mv).is_ok())
+            .collect()
+    }
+
+    /// Count the legal moves, without allocating a vector like [`legal_moves`] does.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::kiwipete();
+    ///
+    /// assert_eq!(board.count_legal_moves(), 48);
+    /// assert_eq!(board.count_legal_moves(), board.legal_moves().len());
+    /// ```
+    ///
+    /// [`legal_moves`]: #method.legal_moves
+    pub fn count_legal_moves(&self) -> usize {
+        let mut moves = [ChessMove::NULL_MOVE; 256];
+        let count = self.generate_moves(!EMPTY, &mut moves);
+
+        moves[..count]
+            .iter()
+            .filter(|mv| self.make_move_new(**mv).is_ok())
+            .count()
+    }
+
+    /// Generate the legal [`ChessMove`]'s of the piece on a given square. Only the moves of that
+    /// piece are generated, restricted by pins and checks the same way as [`generate_legal_into`].
+    /// Returns no moves if the square is empty or the piece belongs to the opponent.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(
+    ///     board.legal_moves_from(Square::E2),
+    ///     vec![ChessMove::new(Square::E2, Square::E3), ChessMove::new(Square::E2, Square::E4)]
+    /// );
+    /// assert!(board.legal_moves_from(Square::E1).is_empty());
+    /// ```
+    ///
+    /// [`generate_legal_into`]: #method.generate_legal_into
+    pub fn legal_moves_from(&self, square: Square) -> Vec<ChessMove> {
+        if self.color_on(square) != Some(self.side_to_move) {
+            return Vec::new();
+        }
+
+        let mut moves = Vec::new();
+        self.generate_legal_from_into(BitBoard::from_square(square), &mut moves);
+
+        moves
+    }
+
+    /// Get the legal destination squares of the piece on a given square, see
+    /// [`legal_moves_from`]. Returns `EMPTY` if the square is empty, the piece belongs to the
+    /// opponent or it has no legal moves.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(
+    ///     board.legal_destinations(Square::B1),
+    ///     BitBoard::from_square(Square::A3) | BitBoard::from_square(Square::C3)
+    /// );
+    /// assert_eq!(board.legal_destinations(Square::A1), EMPTY);
+    /// ```
+    ///
+    /// [`legal_moves_from`]: #method.legal_moves_from
+    pub fn legal_destinations(&self, square: Square) -> BitBoard {
+        self.legal_moves_from(square)
+            .into_iter()
+            .map(|mv| mv.get_move().1)
+            .collect()
+    }
+
+    /// Generate the legal [`ChessMove`]'s and append them to `out`.
+    ///
+    /// Unlike [`legal_moves`] no move is tried on a copy of the [`Board`], instead pinned pieces
+    /// only move along their pin, out of check only moves capturing the checking piece or
+    /// blocking the check are generated, and the king never steps onto an attacked square. The
+    /// moves are the same as those of [`legal_moves`], though not necessarily in the same order.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::kiwipete();
+    /// let mut moves = Vec::new();
+    ///
+    /// board.generate_legal_into(&mut moves);
+    ///
+    /// assert_eq!(moves.len(), 48);
+    /// ```
+    ///
+    /// [`legal_moves`]: #method.legal_moves
+    pub fn generate_legal_into(&self, out: &mut Vec<ChessMove>) {
+        self.generate_legal_from_into(!EMPTY, out);
+    }
+
+    /// Generate the legal [`ChessMove`]'s of the pieces on the squares set in `from` and append
+    /// them to `out`.
+    fn generate_legal_from_into(&self, from: BitBoard, out: &mut Vec<ChessMove>) {
+        let allied_pieces = self.occupancy(self.side_to_move);
+        let opponent_occupancy = self.occupancy(!self.side_to_move);
+        let combined = self.combined();
+
+        let king_square = self
+            .pieces_color(Piece::King, self.side_to_move)
+            .to_square();
+        let checkers = if self.in_check() {
+            self.get_attackers(king_square)
+        } else {
+            EMPTY
+        };
+
+        if from.is_set(king_square) {
+            for dest in get_king_moves(king_square)
+                & !allied_pieces
+                & !self.attacked_squares_excluding_king()
+            {
+                out.push(ChessMove::new(king_square, dest));
+            }
+
+            for dest in self.generate_castling_moves() {
+                out.push(ChessMove::new(king_square, dest));
+            }
+        }
+
+        if checkers.more_than_one() {
+            return;
+        }
+
+        // In check, the other pieces have to capture the checking piece or block the check.
+        let target = if checkers.is_zero() {
+            !allied_pieces
+        } else {
+            checkers | self.block_squares()
+        };
+
+        let pinned = self.pinned() & allied_pieces;
+        let pin_mask = |src: Square| {
+            if pinned.is_set(src) {
+                get_tangent(king_square, src)
+            } else {
+                !EMPTY
+            }
+        };
+
+        for piece in [Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen] {
+            for src in self.pieces_color(piece, self.side_to_move) & from {
+                let moves = match piece {
+                    Piece::Knight => get_knight_moves(src),
+                    Piece::Bishop => get_bishop_moves(src, combined),
+                    Piece::Rook => get_rook_moves(src, combined),
+                    Piece::Queen => get_bishop_moves(src, combined) | get_rook_moves(src, combined),
+                    _ => unreachable!(),
+                } & target
+                    & pin_mask(src);
+
+                for dest in moves {
+                    out.push(ChessMove::new(src, dest));
+                }
+            }
+        }
+
+        for src in self.movable_pawns() & from {
+            let pawn_moves = if BitBoard::from_square(src.wrapping_forward(self.side_to_move))
+                & !combined
+                != EMPTY
+            {
+                get_pawn_moves(src, self.side_to_move) & !combined
+            } else {
+                EMPTY
+            } | (get_pawn_attacks(src, self.side_to_move) & opponent_occupancy);
+
+            for dest in pawn_moves & target & pin_mask(src) {
+                if self.is_promotion(dest) {
+                    for promotion in [Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen] {
+                        out.push(ChessMove::new_promotion(src, dest, promotion));
+                    }
+                } else {
+                    out.push(ChessMove::new(src, dest));
+                }
+            }
+        }
+
+        if let Some(en_passant) = self.en_passant_square {
+            let captured = en_passant.wrapping_backward(self.side_to_move);
+
+            // Capturing en passant may also get out of a check by the captured pawn itself.
+            if target.is_set(en_passant) || checkers == BitBoard::from_square(captured) {
+                for src in get_pawn_attacks(en_passant, !self.side_to_move)
+                    & self.pieces_color(Piece::Pawn, self.side_to_move)
+                    & from
+                {
+                    if !self.en_passant_exposes_king(src, en_passant) {
+                        out.push(ChessMove::new(src, en_passant));
+                    }
+                }
+            }
+        }
+    }
+
     /// Generate a psudo-legal [`ChessMove`]'s and put them into `moves`.
     ///
+    /// Only moves landing on a square set in `mask` are generated, see [`generate_moves_vec`].
+    ///
     /// # Example
     /// ```
     /// use chessframe::{board::Board, bitboard::EMPTY, chess_move::ChessMove};
@@ -1405,6 +3025,8 @@ impl Board {
     ///     20
     /// );
     /// ```
+    ///
+    /// [`generate_moves_vec`]: #method.generate_moves_vec
     #[rustfmt::skip]
     pub fn generate_moves(&self, mask: BitBoard, moves: &mut [ChessMove]) -> usize {
         let allied_pieces = self.occupancy(self.side_to_move);
@@ -1425,7 +3047,7 @@ impl Board {
             };
         }
 
-        if self.check >= 2 {
+        if self.is_double_check() {
             generate_moves!(Piece::King, get_king_moves);
 
             return index;
@@ -1441,10 +3063,7 @@ impl Board {
 
         generate_moves!(Piece::King, |src| get_king_moves(src) | self.generate_castling_moves());
 
-        for src in self
-            .pieces_color(Piece::Pawn, self.side_to_move)
-            .into_iter()
-        {
+        for src in self.movable_pawns() {
             let pawn_moves = if BitBoard::from_square(src.wrapping_forward(self.side_to_move)) & !combined != EMPTY {
                 get_pawn_moves(src, self.side_to_move) & !combined
             } else {
@@ -1475,6 +3094,34 @@ impl Board {
         index
     }
 
+    /// Generate psudo-legal [`ChessMove`]'s into an [`ArrayVec`] on the stack, avoiding the heap
+    /// allocation of [`generate_moves_vec`]. The moves are the same, in the same order, as those
+    /// generated by [`generate_moves`]. Requires the `arrayvec` feature.
+    ///
+    /// Only moves landing on a square set in `mask` are generated, see [`generate_moves_vec`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, bitboard::EMPTY};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.generate_moves_arrayvec(!EMPTY).len(), 20);
+    /// ```
+    ///
+    /// [`ArrayVec`]: arrayvec::ArrayVec
+    /// [`generate_moves_vec`]: #method.generate_moves_vec
+    /// [`generate_moves`]: #method.generate_moves
+    #[cfg(feature = "arrayvec")]
+    pub fn generate_moves_arrayvec(&self, mask: BitBoard) -> ArrayVec<ChessMove, MAX_MOVES> {
+        let mut moves = ArrayVec::from([ChessMove::NULL_MOVE; MAX_MOVES]);
+
+        let len = self.generate_moves(mask, &mut moves);
+        moves.truncate(len);
+
+        moves
+    }
+
     #[inline(always)]
     fn push_move(moves: &mut [ChessMove], index: &mut usize, mv: ChessMove) {
         debug_assert!(*index < moves.len());
@@ -1535,33 +3182,705 @@ impl Board {
         attackers
     }
 
-    /// Generate all pawn moves.
+    /// Get the attackers of both colors for a given square, using `occupied` as the blockers for
+    /// sliding pieces.
     ///
     /// # Example
     /// ```
-    /// use chessframe::{board::Board, bitboard::BitBoard};
+    /// use chessframe::{board::Board, bitboard::BitBoard, square::Square};
     ///
-    /// let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    /// let fen = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1";
     /// let board = Board::from_fen(fen);
     ///
-    /// assert_eq!(board.generate_pawn_moves(), BitBoard(0xFFFF0000))
+    /// assert_eq!(board.attackers_to(Square::D5, board.combined()), BitBoard::from_square(Square::E4));
+    /// assert_eq!(board.attackers_to(Square::E4, board.combined()), BitBoard::from_square(Square::D5));
     /// ```
-    pub fn generate_pawn_moves(&self) -> BitBoard {
-        let mut moves = BitBoard::default();
+    #[inline]
+    pub fn attackers_to(&self, square: Square, occupied: BitBoard) -> BitBoard {
+        let bishops = self.pieces(Piece::Bishop) | self.pieces(Piece::Queen);
+        let rooks = self.pieces(Piece::Rook) | self.pieces(Piece::Queen);
+
+        (get_pawn_attacks(square, Color::White) & self.pieces_color(Piece::Pawn, Color::Black))
+            | (get_pawn_attacks(square, Color::Black)
+                & self.pieces_color(Piece::Pawn, Color::White))
+            | (get_knight_moves(square) & self.pieces(Piece::Knight))
+            | (get_bishop_moves(square, occupied) & bishops)
+            | (get_rook_moves(square, occupied) & rooks)
+            | (get_king_moves(square) & self.pieces(Piece::King))
+    }
 
-        for square in self.pieces_color(Piece::Pawn, self.side_to_move) {
-            if (BitBoard::from_square(square.wrapping_forward(self.side_to_move))
-                & !self.combined())
-                != EMPTY
-            {
-                moves |= get_pawn_moves(square, self.side_to_move) & !self.combined();
-            }
+    /// Get the pieces defending the piece on a given square, that is the pieces of the same color
+    /// attacking the square. Returns `EMPTY` if the square is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/4p3/3n4/8/8/8/4K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.defenders_of(Square::D5), BitBoard::from_square(Square::E6));
+    /// assert_eq!(board.defenders_of(Square::E6), EMPTY);
+    /// assert_eq!(board.defenders_of(Square::E4), EMPTY);
+    /// ```
+    pub fn defenders_of(&self, square: Square) -> BitBoard {
+        match self.color_on(square) {
+            Some(color) => self.attackers_to(square, self.combined()) & self.occupancy(color),
+            None => EMPTY,
+        }
+    }
+
+    /// Get the squares attacked by the piece on a given square, using the current occupancy as the
+    /// blockers for sliding pieces. Returns `EMPTY` if the square is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(
+    ///     board.attacks_from(Square::E2),
+    ///     BitBoard::from_square(Square::D3) | BitBoard::from_square(Square::F3)
+    /// );
+    /// assert_eq!(
+    ///     board.attacks_from(Square::A1),
+    ///     BitBoard::from_square(Square::A2) | BitBoard::from_square(Square::B1)
+    /// );
+    /// assert_eq!(board.attacks_from(Square::E4), EMPTY);
+    /// ```
+    pub fn attacks_from(&self, square: Square) -> BitBoard {
+        let (Some(piece), Some(color)) = (self.get_piece(square), self.color_on(square)) else {
+            return EMPTY;
+        };
 
-            moves |=
-                get_pawn_attacks(square, self.side_to_move) & self.occupancy(!self.side_to_move);
+        let blockers = self.combined();
+
+        match piece {
+            Piece::Pawn => get_pawn_attacks(square, color),
+            Piece::Knight => get_knight_moves(square),
+            Piece::Bishop => get_bishop_moves(square, blockers),
+            Piece::Rook => get_rook_moves(square, blockers),
+            Piece::Queen => get_bishop_moves(square, blockers) | get_rook_moves(square, blockers),
+            Piece::King => get_king_moves(square),
         }
+    }
 
-        moves | self.generate_en_passant()
+    /// Get the squares attacked by all pieces of a given [`Color`], using the current occupancy as
+    /// the blockers for sliding pieces. Squares with pieces of the same color on them count as
+    /// attacked, as they are defended.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.attacks_by(Color::White).count_ones(), 22);
+    /// assert!(board.attacks_by(Color::Black).is_set(Square::F6));
+    /// assert!(!board.attacks_by(Color::Black).is_set(Square::E5));
+    /// ```
+    pub fn attacks_by(&self, color: Color) -> BitBoard {
+        self.attacks_with_blockers(color, self.combined())
+    }
+
+    /// Get the squares attacked by at least two pieces of a given [`Color`], using the current
+    /// occupancy as the blockers for sliding pieces, so batteries only count once.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K2R w - - 0 1");
+    /// let attacked = board.multiple_attackers(Color::White);
+    ///
+    /// assert!(attacked.is_set(Square::F1));
+    /// assert!(!attacked.is_set(Square::H8));
+    /// ```
+    pub fn multiple_attackers(&self, color: Color) -> BitBoard {
+        let mut once = EMPTY;
+        let mut twice = EMPTY;
+
+        for square in self.occupancy(color) {
+            let attacks = self.attacks_from(square);
+
+            twice |= once & attacks;
+            once |= attacks;
+        }
+
+        twice
+    }
+
+    /// Get the squares attacked by the opponent of the side to move, the squares where a piece of
+    /// the side to move could be captured. Same as [`attacks_by`] for the opponent.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/3n4/8/8/8/4K3 w - - 0 1");
+    ///
+    /// assert!(board.threatened_squares().is_set(Square::E3));
+    /// assert!(board.threatened_squares().is_set(Square::D7));
+    /// assert!(!board.threatened_squares().is_set(Square::E1));
+    /// ```
+    ///
+    /// [`attacks_by`]: #method.attacks_by
+    pub fn threatened_squares(&self) -> BitBoard {
+        self.attacks_by(!self.side_to_move)
+    }
+
+    /// Get the squares attacked by the opponent of the side to move, with the king of the side to
+    /// move removed from the blockers of sliding pieces.
+    ///
+    /// A king stepping away from a slider along its ray stays in check, so looking through the
+    /// king makes the squares behind it attacked too. The king can move to the squares around it
+    /// that aren't set here.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1");
+    ///
+    /// assert!(board.attacked_squares_excluding_king().is_set(Square::F1));
+    /// assert!(!board.attacked_squares_excluding_king().is_set(Square::F2));
+    /// ```
+    pub fn attacked_squares_excluding_king(&self) -> BitBoard {
+        let blockers = self.combined() & !self.pieces_color(Piece::King, self.side_to_move);
+
+        self.attacks_with_blockers(!self.side_to_move, blockers)
+    }
+
+    /// Get the squares attacked by all pieces of a given [`Color`], using `blockers` as the
+    /// blockers for sliding pieces.
+    fn attacks_with_blockers(&self, color: Color, blockers: BitBoard) -> BitBoard {
+        let mut attacked = EMPTY;
+
+        for square in self.pieces_color(Piece::Pawn, color) {
+            attacked |= get_pawn_attacks(square, color);
+        }
+        for square in self.pieces_color(Piece::Knight, color) {
+            attacked |= get_knight_moves(square);
+        }
+        for square in
+            self.pieces_color(Piece::Bishop, color) | self.pieces_color(Piece::Queen, color)
+        {
+            attacked |= get_bishop_moves(square, blockers);
+        }
+        for square in self.pieces_color(Piece::Rook, color) | self.pieces_color(Piece::Queen, color)
+        {
+            attacked |= get_rook_moves(square, blockers);
+        }
+        for square in self.pieces_color(Piece::King, color) {
+            attacked |= get_king_moves(square);
+        }
+
+        attacked
+    }
+
+    /// Simulate the full exchange on a given square, with both sides always recapturing with their
+    /// least valuable attacker, and return the order in which the pieces would capture.
+    ///
+    /// The side to move captures first. Pieces uncovered by earlier captures (x-rays) join the
+    /// exchange, and a king only captures if the square is no longer defended.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color, piece::Piece, square::Square};
+    ///
+    /// let fen = "4k3/8/4p3/3p4/4P3/8/8/4K3 w - - 0 1";
+    /// let board = Board::from_fen(fen);
+    ///
+    /// assert_eq!(
+    ///     board.capture_sequence(Square::D5),
+    ///     vec![(Color::White, Piece::Pawn), (Color::Black, Piece::Pawn)]
+    /// );
+    /// ```
+    pub fn capture_sequence(&self, square: Square) -> Vec<(Color, Piece)> {
+        let mut sequence = Vec::new();
+        let mut occupied = self.combined();
+        let mut color = self.side_to_move;
+
+        loop {
+            let attackers = self.attackers_to(square, occupied) & occupied;
+
+            let Some((piece, attacker)) = PIECES.iter().find_map(|piece| {
+                let candidates = attackers & self.pieces_color(*piece, color);
+                (candidates != EMPTY).then(|| (*piece, candidates.to_square()))
+            }) else {
+                break;
+            };
+
+            if piece == Piece::King && attackers & self.occupancy(!color) != EMPTY {
+                break;
+            }
+
+            sequence.push((color, piece));
+            occupied ^= BitBoard::from_square(attacker);
+            color = !color;
+        }
+
+        sequence
+    }
+
+    /// Check if the static exchange evaluation of a [`ChessMove`] is at least `threshold`.
+    ///
+    /// Both sides keep recapturing on the destination square with their least valuable attacker,
+    /// and may stop whenever continuing would lose material. Returns as soon as the outcome
+    /// relative to `threshold` is known, without computing the exact exchange value.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/4p3/3p4/4P3/8/8/4K3 w - - 0 1");
+    /// let mv = ChessMove::new(Square::E4, Square::D5);
+    ///
+    /// assert!(board.see_ge(mv, 0));
+    /// assert!(!board.see_ge(mv, 1));
+    /// ```
+    pub fn see_ge(&self, mv: ChessMove, threshold: i32) -> bool {
+        let (from, to) = mv.get_move();
+
+        let Some(piece) = self.get_piece(from) else {
+            return false;
+        };
+
+        let en_passant = piece == Piece::Pawn && Some(to) == self.en_passant_square();
+
+        let captured = if en_passant {
+            Piece::Pawn.value()
+        } else {
+            self.get_piece(to).map_or(0, Piece::value)
+        };
+
+        let mut swap = captured - threshold;
+        if swap < 0 {
+            return false;
+        }
+
+        swap = piece.value() - swap;
+        if swap <= 0 {
+            return true;
+        }
+
+        let bishops = self.pieces(Piece::Bishop) | self.pieces(Piece::Queen);
+        let rooks = self.pieces(Piece::Rook) | self.pieces(Piece::Queen);
+
+        let mut occupied =
+            self.combined() ^ BitBoard::from_square(from) ^ BitBoard::from_square(to);
+        if en_passant {
+            // The captured pawn is behind the destination square, uncovering anything behind it.
+            occupied ^= BitBoard::from_square(to.wrapping_backward(self.side_to_move));
+        }
+        let mut attackers = self.attackers_to(to, occupied);
+        let mut color = self.side_to_move;
+        let mut result = true;
+
+        loop {
+            color = !color;
+            attackers &= occupied;
+
+            let color_attackers = attackers & self.occupancy(color);
+            if color_attackers == EMPTY {
+                break;
+            }
+
+            result = !result;
+
+            let Some(attacker) = PIECES
+                .iter()
+                .find(|piece| color_attackers & self.pieces(**piece) != EMPTY)
+            else {
+                break;
+            };
+
+            if *attacker == Piece::King {
+                // The king can only recapture if the square is no longer defended.
+                return if attackers & self.occupancy(!color) != EMPTY {
+                    !result
+                } else {
+                    result
+                };
+            }
+
+            swap = attacker.value() - swap;
+            if swap < result as i32 {
+                break;
+            }
+
+            occupied ^=
+                BitBoard::from_square((color_attackers & self.pieces(*attacker)).to_square());
+
+            match attacker {
+                Piece::Pawn | Piece::Bishop => {
+                    attackers |= get_bishop_moves(to, occupied) & bishops
+                }
+                Piece::Rook => attackers |= get_rook_moves(to, occupied) & rooks,
+                Piece::Queen => {
+                    attackers |= (get_bishop_moves(to, occupied) & bishops)
+                        | (get_rook_moves(to, occupied) & rooks)
+                }
+                _ => {}
+            }
+        }
+
+        result
+    }
+
+    /// Get the material value of the knights, bishops, rooks and queens of a given [`Color`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.non_pawn_material(Color::White), 0);
+    /// assert_eq!(Board::default().non_pawn_material(Color::Black), 3150);
+    /// ```
+    pub fn non_pawn_material(&self, color: Color) -> i32 {
+        [Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen]
+            .iter()
+            .map(|piece| self.pieces_color(*piece, color).count_ones() as i32 * piece.value())
+            .sum()
+    }
+
+    /// Check if the [`Board`] is in the endgame: neither side has a queen, or the non-pawn
+    /// material of both sides together, see [`non_pawn_material`], is below
+    /// [`ENDGAME_MATERIAL`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::from_fen("4k3/5pp1/8/8/8/8/r4PP1/3R2K1 w - - 0 1");
+    ///
+    /// assert!(board.is_endgame());
+    /// assert!(!Board::default().is_endgame());
+    /// ```
+    ///
+    /// [`non_pawn_material`]: #method.non_pawn_material
+    pub fn is_endgame(&self) -> bool {
+        self.pieces(Piece::Queen) == EMPTY
+            || self.non_pawn_material(Color::White) + self.non_pawn_material(Color::Black)
+                < ENDGAME_MATERIAL
+    }
+
+    /// Get the material balance, the material of White less the material of Black, in
+    /// centipawns using [`Piece::value`]. The material is tracked as pieces are added and removed,
+    /// so this doesn't count the pieces.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.material_balance(), 500);
+    /// assert_eq!(Board::default().material_balance(), 0);
+    /// ```
+    #[inline]
+    pub fn material_balance(&self) -> i32 {
+        self.material[Color::White.to_index()] - self.material[Color::Black.to_index()]
+    }
+
+    /// Get the stand-pat score of the [`Board`] for a quiescence search: the score of the
+    /// position by `eval` from the point of view of the side to move, if it were to stop capturing
+    /// now. A capture only has to be searched if it could raise the score above it.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, evaluation::MaterialEvaluator};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 b - - 0 1");
+    ///
+    /// assert_eq!(Board::default().stand_pat(&MaterialEvaluator), 0);
+    /// assert!(board.stand_pat(&MaterialEvaluator) < -400);
+    /// ```
+    #[inline]
+    pub fn stand_pat(&self, eval: &dyn Evaluator) -> i32 {
+        eval.evaluate(self)
+    }
+
+    /// Get the mobility of a given [`Color`]: the number of squares its knights, bishops, rooks,
+    /// queens and king can move to, not counting squares occupied by its own pieces. The pushes
+    /// and captures of its pawns are only counted if `include_pawns` is set. Castling and en
+    /// passant are left out, and checks and pins are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, color::Color};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.mobility(Color::White, false), 4);
+    /// assert_eq!(board.mobility(Color::White, true), 20);
+    /// assert_eq!(board.mobility(Color::White, false), board.mobility(Color::Black, false));
+    /// ```
+    pub fn mobility(&self, color: Color, include_pawns: bool) -> i32 {
+        let blockers = self.combined();
+        let targets = !self.occupancy(color);
+
+        let mut mobility = 0;
+
+        if include_pawns {
+            for square in self.pieces_color(Piece::Pawn, color) {
+                let pushes = if blockers.is_set(square.wrapping_forward(color)) {
+                    EMPTY
+                } else {
+                    get_pawn_moves(square, color) & !blockers
+                };
+                let captures = get_pawn_attacks(square, color) & self.occupancy(!color);
+
+                mobility += (pushes | captures).count_ones() as i32;
+            }
+        }
+
+        for piece in [
+            Piece::Knight,
+            Piece::Bishop,
+            Piece::Rook,
+            Piece::Queen,
+            Piece::King,
+        ] {
+            for square in self.pieces_color(piece, color) {
+                let moves = match piece {
+                    Piece::Knight => get_knight_moves(square),
+                    Piece::Bishop => get_bishop_moves(square, blockers),
+                    Piece::Rook => get_rook_moves(square, blockers),
+                    Piece::Queen => {
+                        get_bishop_moves(square, blockers) | get_rook_moves(square, blockers)
+                    }
+                    Piece::King => get_king_moves(square),
+                    _ => unreachable!(),
+                };
+
+                mobility += (moves & targets).count_ones() as i32;
+            }
+        }
+
+        mobility
+    }
+
+    /// Get the outposts of a given [`Color`]: squares in the opponent's half of the board that are
+    /// defended by one of its pawns, and can never be attacked by an enemy pawn.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, board::Board, color::Color, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/pp4pp/3p4/4p3/4P3/8/PP4PP/4K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.outposts(Color::White), BitBoard::from_square(Square::D5));
+    /// assert_eq!(board.outposts(Color::Black), BitBoard::from_square(Square::D4));
+    /// ```
+    pub fn outposts(&self, color: Color) -> BitBoard {
+        let mut defended = EMPTY;
+        for square in self.pieces_color(Piece::Pawn, color) {
+            defended |= get_pawn_attacks(square, color);
+        }
+
+        let mut enemy_attacks = EMPTY;
+        for square in self.pieces_color(Piece::Pawn, !color) {
+            enemy_attacks |= get_pawn_attacks(square, !color);
+        }
+
+        // Enemy pawns can attack every square in front of the ones they attack now.
+        let (enemy_span, opponent_half) = match color {
+            Color::White => (enemy_attacks.fill_south(), BitBoard(0xFFFFFFFF00000000)),
+            Color::Black => (enemy_attacks.fill_north(), BitBoard(0x00000000FFFFFFFF)),
+        };
+
+        defended & opponent_half & !enemy_span
+    }
+
+    /// Get the hanging pieces of a given [`Color`]: pieces attacked by the opponent that are
+    /// either undefended, or attacked by a less valuable piece. Kings are never hanging.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, color::Color, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/3r4/8/1B6/8/4K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.hanging_pieces(Color::White), EMPTY);
+    /// assert_eq!(board.hanging_pieces(Color::Black), BitBoard::from_square(Square::D5));
+    /// ```
+    pub fn hanging_pieces(&self, color: Color) -> BitBoard {
+        let blockers = self.combined();
+        let mut hanging = EMPTY;
+
+        for square in self.occupancy(color) & !self.pieces(Piece::King) {
+            let attackers = self.attackers_to(square, blockers) & self.occupancy(!color);
+            if attackers == EMPTY {
+                continue;
+            }
+
+            let defended = self.defenders_of(square) != EMPTY;
+
+            // A king can't capture a defended piece.
+            let cheapest = (attackers & !self.pieces(Piece::King))
+                .into_iter()
+                .filter_map(|square| self.get_piece(square))
+                .map(Piece::value)
+                .min();
+
+            let value = self.get_piece(square).map_or(0, Piece::value);
+
+            if !defended || cheapest.is_some_and(|cheapest| cheapest < value) {
+                hanging |= BitBoard::from_square(square);
+            }
+        }
+
+        hanging
+    }
+
+    /// Get a key identifying the material on the board, independent of where the pieces are.
+    /// Boards with the same piece counts for both colors have the same key.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    /// let other = Board::from_fen("8/8/2k5/8/8/5R2/8/6K1 b - - 0 1");
+    ///
+    /// assert_eq!(board.material_key(), other.material_key());
+    /// assert_ne!(board.material_key(), Board::default().material_key());
+    /// ```
+    pub fn material_key(&self) -> u64 {
+        let mut key = 0;
+
+        for color in COLORS {
+            for piece in [
+                Piece::Pawn,
+                Piece::Knight,
+                Piece::Bishop,
+                Piece::Rook,
+                Piece::Queen,
+            ] {
+                let count = self.pieces_color(piece, color).count_ones() as u64;
+                key |= count << (4 * (color.to_index() * 5 + piece.to_index()));
+            }
+        }
+
+        key
+    }
+
+    /// Get a human-readable signature of the material on the board, such as `"KRvK"`. The white
+    /// pieces come first, ordered from the king down to the pawns.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::board::Board;
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    ///
+    /// assert_eq!(board.material_signature(), "KRvK");
+    /// assert_eq!(Board::default().material_signature(), "KQRRBBNNPPPPPPPPvKQRRBBNNPPPPPPPP");
+    /// ```
+    pub fn material_signature(&self) -> String {
+        let side = |color: Color| {
+            let mut signature = String::new();
+
+            for piece in PIECES.iter().rev() {
+                let count = self.pieces_color(*piece, color).count_ones() as usize;
+                let symbol = piece.to_fen().to_ascii_uppercase();
+                signature.extend(std::iter::repeat_n(symbol, count));
+            }
+
+            signature
+        };
+
+        format!("{}v{}", side(Color::White), side(Color::Black))
+    }
+
+    /// Generate all pawn moves.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, bitboard::BitBoard};
+    ///
+    /// let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    /// let board = Board::from_fen(fen);
+    ///
+    /// assert_eq!(board.generate_pawn_moves(), BitBoard(0xFFFF0000))
+    /// ```
+    pub fn generate_pawn_moves(&self) -> BitBoard {
+        self.pawn_pushes(self.side_to_move) | self.pawn_captures(self.side_to_move)
+    }
+
+    /// Get the squares the pawns of a given [`Color`] can push to, both single and double pushes.
+    /// A double push needs both squares in front of the pawn to be empty.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::BitBoard, board::Board, color::Color};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.pawn_pushes(Color::White), BitBoard(0x00000000FFFF0000));
+    /// assert_eq!(board.pawn_pushes(Color::Black), BitBoard(0x0000FFFF00000000));
+    /// ```
+    pub fn pawn_pushes(&self, color: Color) -> BitBoard {
+        let empty = !self.combined();
+
+        let mut pushes = EMPTY;
+
+        for square in self.movable_pawns_color(color) {
+            if empty.is_set(square.wrapping_forward(color)) {
+                pushes |= get_pawn_moves(square, color) & empty;
+            }
+        }
+
+        pushes
+    }
+
+    /// Get the squares the pawns of a given [`Color`] can capture on, the opponent pieces they
+    /// attack and the en passant square if `color` is the side to move.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{bitboard::{BitBoard, EMPTY}, board::Board, color::Color, square::Square};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.pawn_captures(Color::White), EMPTY);
+    ///
+    /// let board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1");
+    ///
+    /// assert_eq!(board.pawn_captures(Color::White), BitBoard::from_square(Square::D6));
+    /// ```
+    pub fn pawn_captures(&self, color: Color) -> BitBoard {
+        let mut captures = EMPTY;
+
+        for square in self.movable_pawns_color(color) {
+            captures |= get_pawn_attacks(square, color);
+        }
+
+        captures &= self.occupancy(!color);
+
+        if color == self.side_to_move {
+            captures |= self.generate_en_passant();
+        }
+
+        captures
+    }
+
+    /// Get the pawns of the side to move that aren't on the last rank. Such pawns can only exist in
+    /// constructed positions, and moving them forward would wrap around the board.
+    #[inline]
+    fn movable_pawns(&self) -> BitBoard {
+        self.movable_pawns_color(self.side_to_move)
+    }
+
+    /// Get the pawns of a given [`Color`] that aren't on the last rank, see [`movable_pawns`].
+    ///
+    /// [`movable_pawns`]: #method.movable_pawns
+    #[inline]
+    fn movable_pawns_color(&self, color: Color) -> BitBoard {
+        self.pieces_color(Piece::Pawn, color) & !get_rank((!color).to_backrank())
     }
 
     /// Generate all en passants.
@@ -1,11 +1,11 @@
 use std::fs::{File, OpenOptions};
-#[cfg(not(feature = "bmi2"))]
-use std::thread;
+use std::{env, thread};
 
 use crate::gen_tables::*;
 
 mod bitboard;
 mod color;
+mod direction;
 mod error;
 mod file;
 mod gen_tables;
@@ -15,8 +15,20 @@ mod square;
 
 fn main() {
     println!("cargo::rerun-if-changed=src/build.rs");
+    println!("cargo::rustc-check-cfg=cfg(use_pext)");
 
-    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR should be set");
+    // Use PEXT based slider lookups when BMI2 is requested, either through the `bmi2` feature or
+    // by compiling with the `bmi2` target feature, and the target is x86_64.
+    let use_pext = env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|arch| arch == "x86_64")
+        && (env::var("CARGO_FEATURE_BMI2").is_ok()
+            || env::var("CARGO_CFG_TARGET_FEATURE")
+                .is_ok_and(|features| features.split(',').any(|feature| feature == "bmi2")));
+
+    if use_pext {
+        println!("cargo::rustc-cfg=use_pext");
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set");
     let mut file = File::create(format!("{}/tables.rs", out_dir)).unwrap();
 
     write_files(&mut file);
@@ -31,6 +43,8 @@ fn main() {
 
     write_tangent(&mut file);
 
+    write_center_distance(&mut file);
+
     write_pawn_moves(&mut file);
 
     write_pawn_attacks(&mut file);
@@ -56,8 +70,11 @@ fn main() {
     };
 
     if file.metadata().expect("file metadata not found").len() != 4 {
-        #[cfg(not(feature = "bmi2"))]
-        {
+        if use_pext {
+            write_bishop_pext(&mut file);
+
+            write_rook_pext(&mut file);
+        } else {
             let mut bishop_file = file.try_clone().unwrap();
             let bishop_thread = thread::spawn(move || {
                 write_bishop_moves(&mut bishop_file);
@@ -69,12 +86,5 @@ fn main() {
             let _ = bishop_thread.join();
             let _ = rook_thread.join();
         }
-
-        #[cfg(feature = "bmi2")]
-        {
-            write_bishop_pext(&mut file);
-
-            write_rook_pext(&mut file);
-        }
     }
 }
@@ -1,3 +1,5 @@
+use std::ops::{BitAnd, BitOr, Not};
+
 use crate::{color::Color, file::File, square::Square};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
@@ -9,6 +11,34 @@ impl Default for CastlingRights {
     }
 }
 
+impl BitAnd for CastlingRights {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self::Output {
+        CastlingRights(self.0 & other.0)
+    }
+}
+
+impl BitOr for CastlingRights {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self::Output {
+        CastlingRights(self.0 | other.0)
+    }
+}
+
+impl Not for CastlingRights {
+    type Output = Self;
+
+    /// Get the castling rights that aren't set.
+    #[inline]
+    fn not(self) -> Self::Output {
+        CastlingRights(!self.0 & CastlingRights::default().0)
+    }
+}
+
 impl CastlingRights {
     const KINGSIDE: u8 = 0b0001;
     const QUEENSIDE: u8 = 0b0010;
@@ -48,20 +78,30 @@ impl CastlingRights {
     }
 
     /// Create a new [`CastlingRights`] struct from a part of a FEN string.
+    ///
+    /// Besides `KQkq` and `-`, the rook files of X-FEN are accepted, with the king on the e-file:
+    /// `F`-`H` castle kingside and `A`-`D` queenside. Any other characters are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::castling_rights::CastlingRights;
+    ///
+    /// assert_eq!(CastlingRights::from_fen("HAha"), CastlingRights::from_fen("KQkq"));
+    /// assert_eq!(CastlingRights::from_fen("-"), CastlingRights::new());
+    /// ```
     pub fn from_fen(fen: &str) -> Self {
         let mut castling_rights = CastlingRights::new();
 
-        if fen.contains('K') {
-            castling_rights = castling_rights.add_right(Color::White, true);
-        }
-        if fen.contains('Q') {
-            castling_rights = castling_rights.add_right(Color::White, false);
-        }
-        if fen.contains('k') {
-            castling_rights = castling_rights.add_right(Color::Black, true);
-        }
-        if fen.contains('q') {
-            castling_rights = castling_rights.add_right(Color::Black, false);
+        for ch in fen.chars() {
+            let (color, kingside) = match ch {
+                'K' | 'F'..='H' => (Color::White, true),
+                'Q' | 'A'..='D' => (Color::White, false),
+                'k' | 'f'..='h' => (Color::Black, true),
+                'q' | 'a'..='d' => (Color::Black, false),
+                _ => continue,
+            };
+
+            castling_rights = castling_rights.add_right(color, kingside);
         }
 
         castling_rights
@@ -83,6 +123,9 @@ impl CastlingRights {
         if self.can_castle(Color::Black, false) {
             fen.push('q');
         }
+        if fen.is_empty() {
+            fen.push('-');
+        }
 
         fen
     }
@@ -109,12 +152,28 @@ impl CastlingRights {
 
     /// Remove castling rights provided by the `remove` argument.
     pub fn remove(&self, remove: CastlingRights) -> CastlingRights {
-        CastlingRights(self.0 & !remove.0)
+        *self & !remove
     }
 
     /// Add castling rights provided by the `add` argument.
     pub fn add(&self, add: CastlingRights) -> CastlingRights {
-        CastlingRights(self.0 | add.0)
+        *self | add
+    }
+
+    /// Check if all the castling rights of `other` are set.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{castling_rights::CastlingRights, color::Color};
+    ///
+    /// let castling_rights = CastlingRights::from_fen("KQk");
+    ///
+    /// assert!(castling_rights.contains(CastlingRights::from_fen("Qk")));
+    /// assert!(!castling_rights.contains(CastlingRights::from_fen("Kq")));
+    /// assert!(castling_rights.contains(CastlingRights::new()));
+    /// ```
+    pub fn contains(&self, other: CastlingRights) -> bool {
+        *self & other == other
     }
 
     /// Check if a specific color can castle on a specific side.
@@ -140,14 +199,24 @@ impl CastlingRights {
     }
 
     /// Revoke castling rights for a specific color and side.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{castling_rights::CastlingRights, color::Color};
+    ///
+    /// let mut castling_rights = CastlingRights::from_fen("KQkq");
+    /// castling_rights.revoke(Color::White, true);
+    ///
+    /// assert!(!castling_rights.can_castle(Color::White, true));
+    /// assert!(castling_rights.can_castle(Color::White, false));
+    /// assert_eq!(castling_rights, CastlingRights::from_fen("Qkq"));
+    /// ```
     #[rustfmt::skip]
     pub fn revoke(&mut self, color: Color, kingside: bool) {
-        let mut castling_rights = *self;
-
         let offset = if color == Color::Black { CastlingRights::OFFSET } else { 0 };
         let castle_right = if kingside { CastlingRights::KINGSIDE } else { CastlingRights::QUEENSIDE };
 
-        castling_rights.0 &= !(castle_right << offset);
+        self.0 &= !(castle_right << offset);
     }
 
     /// Revoke all castling rights for a specific color.
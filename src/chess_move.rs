@@ -2,7 +2,14 @@ use core::fmt;
 
 use crate::{color::Color, piece::Piece, square::Square};
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+/// A move from one square to another, with an optional promotion piece.
+///
+/// Moves are ordered by their `from` square, then their `to` square and then their promotion,
+/// where no promotion comes first followed by knight, bishop, rook and queen. This order is total
+/// and deterministic, so moves can be sorted or stored in a [`BTreeSet`].
+///
+/// [`BTreeSet`]: std::collections::BTreeSet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct ChessMove {
     pub from: Square,
     pub to: Square,
@@ -75,7 +82,10 @@ impl ChessMove {
     /// let board = Board::from_fen(fen);
     /// let mv = ChessMove::new_promotion(Square::B7, Square::B8, Piece::Queen);
     ///
-    /// assert_eq!(board.make_move_new(mv), Ok(Board::from_fen("1Q5r/2k5/8/K7/8/8/8/1R6 b - - 0 1")));
+    /// let made = board.make_move_new(mv).unwrap();
+    ///
+    /// assert_eq!(made, Board::from_fen("1Q5r/2k5/8/K7/8/8/8/1R6 b - - 0 1"));
+    /// assert!(made.in_check());
     /// ```
     pub fn new_promotion(from: Square, to: Square, promotion: Piece) -> ChessMove {
         ChessMove {
@@ -118,6 +128,65 @@ impl ChessMove {
     pub fn promotion(&self) -> Option<Piece> {
         self.promotion
     }
+
+    /// Encode the move into 16 bits, for compact storage in move lists and transposition tables.
+    ///
+    /// The `from` square is stored in bits 0-5, the `to` square in bits 6-11, the promotion piece
+    /// in bits 12-13 (knight, bishop, rook or queen) and bit 14 flags a promotion. Bit 15 is
+    /// always zero.
+    ///
+    /// # Panics
+    /// Panics if the move promotes to a pawn or a king.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, piece::Piece, square::Square};
+    ///
+    /// let mv = ChessMove::new(Square::E2, Square::E4);
+    /// assert_eq!(mv.to_u16(), 0b0000_011100_001100);
+    ///
+    /// let mv = ChessMove::new_promotion(Square::B7, Square::B8, Piece::Queen);
+    /// assert_eq!(ChessMove::from_u16(mv.to_u16()), mv);
+    /// ```
+    pub fn to_u16(self) -> u16 {
+        let promotion = match self.promotion {
+            None => 0,
+            Some(Piece::Knight) => 0b100,
+            Some(Piece::Bishop) => 0b101,
+            Some(Piece::Rook) => 0b110,
+            Some(Piece::Queen) => 0b111,
+            Some(_) => panic!("Invalid promotion piece"),
+        };
+
+        self.from.to_int() as u16 | (self.to.to_int() as u16) << 6 | promotion << 12
+    }
+
+    /// Decode a move encoded by [`ChessMove::to_u16`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, square::Square};
+    ///
+    /// assert_eq!(ChessMove::from_u16(0b0000_011100_001100), ChessMove::new(Square::E2, Square::E4));
+    /// ```
+    pub fn from_u16(value: u16) -> ChessMove {
+        let from = Square::new((value & 0b111111) as u8);
+        let to = Square::new((value >> 6 & 0b111111) as u8);
+
+        let promotion = match value >> 12 & 0b111 {
+            0b100 => Some(Piece::Knight),
+            0b101 => Some(Piece::Bishop),
+            0b110 => Some(Piece::Rook),
+            0b111 => Some(Piece::Queen),
+            _ => None,
+        };
+
+        ChessMove {
+            from,
+            to,
+            promotion,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
@@ -3,7 +3,7 @@ use std::ops::Not;
 use crate::rank::Rank;
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub enum Color {
     #[default]
     White,
@@ -26,6 +26,42 @@ impl Not for Color {
 }
 
 impl Color {
+    /// Convert a [`usize`] to a [`Color`]. If the index is > 1 wrap around.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::color::{COLORS, Color};
+    ///
+    /// assert_eq!(Color::from_index(0), Color::White);
+    /// assert_eq!(Color::from_index(1), Color::Black);
+    ///
+    /// for color in COLORS {
+    ///     assert_eq!(Color::from_index(color.to_index()), color);
+    /// }
+    /// ```
+    #[inline]
+    pub fn from_index(index: usize) -> Color {
+        match index % 2 {
+            0 => Color::White,
+            _ => Color::Black,
+        }
+    }
+
+    /// Iterate over both colors, starting with [`Color::White`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::color::Color;
+    ///
+    /// let colors: Vec<Color> = Color::all().collect();
+    ///
+    /// assert_eq!(colors, vec![Color::White, Color::Black]);
+    /// ```
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Color> {
+        COLORS.into_iter()
+    }
+
     /// Convert [`Color`] to a [`usize`].
     #[inline]
     pub fn to_index(self) -> usize {
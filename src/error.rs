@@ -11,6 +11,15 @@ pub enum Error {
     #[error("The string specified does not contain a valid file")]
     InvalidFile,
 
+    #[error("The string specified does not contain a valid piece")]
+    InvalidPiece,
+
+    #[error("The string specified does not contain a valid FEN")]
+    InvalidFen,
+
+    #[error("The board specified is not a valid position")]
+    InvalidBoard,
+
     #[error("The string specified does not contain a valid move")]
     InvalidMove,
 
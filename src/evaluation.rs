@@ -0,0 +1,137 @@
+use crate::{
+    board::Board,
+    color::{COLORS, Color},
+    piece::PIECES,
+};
+
+/// Scores a [`Board`], for use by a search.
+///
+/// Any closure or function taking a `&Board` and returning an `i32` is an evaluator too.
+pub trait Evaluator {
+    /// Score the [`Board`] in centipawns, from the point of view of the side to move.
+    fn evaluate(&self, board: &Board) -> i32;
+}
+
+impl<F: Fn(&Board) -> i32> Evaluator for F {
+    fn evaluate(&self, board: &Board) -> i32 {
+        self(board)
+    }
+}
+
+/// An [`Evaluator`] counting the material of both sides, using [`Piece::value`], with a bonus or
+/// penalty for every piece from a simple piece-square table.
+///
+/// [`Piece::value`]: crate::piece::Piece::value
+///
+/// # Example
+/// ```
+/// use chessframe::{board::Board, evaluation::{Evaluator, MaterialEvaluator}};
+///
+/// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+///
+/// assert!(MaterialEvaluator.evaluate(&board) > 400);
+/// assert!(MaterialEvaluator.evaluate(&board.make_null_move_new().unwrap()) < -400);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+pub struct MaterialEvaluator;
+
+impl Evaluator for MaterialEvaluator {
+    fn evaluate(&self, board: &Board) -> i32 {
+        let mut score = 0;
+
+        for color in COLORS {
+            let sign = match color {
+                Color::White => 1,
+                Color::Black => -1,
+            };
+
+            for piece in PIECES {
+                for square in board.pieces_color(piece, color) {
+                    // The tables are laid out from White's point of view with the eighth rank
+                    // first, so White's squares are flipped.
+                    let index = match color {
+                        Color::White => square.flip_rank().to_index(),
+                        Color::Black => square.to_index(),
+                    };
+
+                    score += sign * (piece.value() + PIECE_SQUARE_TABLES[piece.to_index()][index]);
+                }
+            }
+        }
+
+        match board.side_to_move {
+            Color::White => score,
+            Color::Black => -score,
+        }
+    }
+}
+
+#[rustfmt::skip]
+const PIECE_SQUARE_TABLES: [[i32; 64]; 6] = [
+    // Pawn
+    [
+         0,  0,  0,  0,  0,  0,  0,  0,
+        50, 50, 50, 50, 50, 50, 50, 50,
+        10, 10, 20, 30, 30, 20, 10, 10,
+         5,  5, 10, 25, 25, 10,  5,  5,
+         0,  0,  0, 20, 20,  0,  0,  0,
+         5, -5,-10,  0,  0,-10, -5,  5,
+         5, 10, 10,-20,-20, 10, 10,  5,
+         0,  0,  0,  0,  0,  0,  0,  0,
+    ],
+    // Knight
+    [
+        -50,-40,-30,-30,-30,-30,-40,-50,
+        -40,-20,  0,  0,  0,  0,-20,-40,
+        -30,  0, 10, 15, 15, 10,  0,-30,
+        -30,  5, 15, 20, 20, 15,  5,-30,
+        -30,  0, 15, 20, 20, 15,  0,-30,
+        -30,  5, 10, 15, 15, 10,  5,-30,
+        -40,-20,  0,  5,  5,  0,-20,-40,
+        -50,-40,-30,-30,-30,-30,-40,-50,
+    ],
+    // Bishop
+    [
+        -20,-10,-10,-10,-10,-10,-10,-20,
+        -10,  0,  0,  0,  0,  0,  0,-10,
+        -10,  0,  5, 10, 10,  5,  0,-10,
+        -10,  5,  5, 10, 10,  5,  5,-10,
+        -10,  0, 10, 10, 10, 10,  0,-10,
+        -10, 10, 10, 10, 10, 10, 10,-10,
+        -10,  5,  0,  0,  0,  0,  5,-10,
+        -20,-10,-10,-10,-10,-10,-10,-20,
+    ],
+    // Rook
+    [
+         0,  0,  0,  0,  0,  0,  0,  0,
+         5, 10, 10, 10, 10, 10, 10,  5,
+        -5,  0,  0,  0,  0,  0,  0, -5,
+        -5,  0,  0,  0,  0,  0,  0, -5,
+        -5,  0,  0,  0,  0,  0,  0, -5,
+        -5,  0,  0,  0,  0,  0,  0, -5,
+        -5,  0,  0,  0,  0,  0,  0, -5,
+         0,  0,  0,  5,  5,  0,  0,  0,
+    ],
+    // Queen
+    [
+        -20,-10,-10, -5, -5,-10,-10,-20,
+        -10,  0,  0,  0,  0,  0,  0,-10,
+        -10,  0,  5,  5,  5,  5,  0,-10,
+         -5,  0,  5,  5,  5,  5,  0, -5,
+          0,  0,  5,  5,  5,  5,  0, -5,
+        -10,  5,  5,  5,  5,  5,  0,-10,
+        -10,  0,  5,  0,  0,  0,  0,-10,
+        -20,-10,-10, -5, -5,-10,-10,-20,
+    ],
+    // King
+    [
+        -30,-40,-40,-50,-50,-40,-40,-30,
+        -30,-40,-40,-50,-50,-40,-40,-30,
+        -30,-40,-40,-50,-50,-40,-40,-30,
+        -30,-40,-40,-50,-50,-40,-40,-30,
+        -20,-30,-30,-40,-40,-30,-30,-20,
+        -10,-20,-20,-20,-20,-20,-20,-10,
+         20, 20,  0,  0,  0,  0, 20, 20,
+         20, 30, 10,  0,  0, 10, 30, 20,
+    ],
+];
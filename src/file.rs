@@ -3,7 +3,7 @@ use std::str::FromStr;
 use crate::error::Error;
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum File {
     A = 0,
     B = 1,
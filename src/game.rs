@@ -17,6 +17,8 @@ pub enum Event {
     DrawByFiftyMoveRule,
     Resignation(Color),
     Timeout(Color),
+    DrawByAgreement,
+    DrawByAdjudication,
 }
 
 impl Event {
@@ -25,7 +27,13 @@ impl Event {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+pub enum GameResult {
+    Win(Color),
+    Draw(Event),
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Game {
     pub board: Board,
     pub history: Vec<Event>,
@@ -34,6 +42,12 @@ pub struct Game {
     half_moves: usize,
 }
 
+impl Default for Game {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
 impl Game {
     /// Create a new [`Game`] with the initial starting position.
     ///
@@ -110,6 +124,58 @@ impl Game {
         self.half_moves
     }
 
+    /// Get the FEN of the current position of the [`Game`], with the half-move clock of the
+    /// [`Game`], which unlike the one of the [`Board`] isn't capped at 255.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, game::Game, square::Square};
+    ///
+    /// let mut game = Game::new();
+    /// game.play_move(ChessMove::new(Square::E2, Square::E4)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     game.fen(),
+    ///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+    /// );
+    /// ```
+    pub fn fen(&self) -> String {
+        let mut fields = self.board.fen_fields();
+        fields.half_moves = self.half_moves as u16;
+
+        fields.to_fen()
+    }
+
+    /// Get the moves played in the [`Game`] in Standard Algebraic Notation, see
+    /// [`Board::to_san`]. Each move is converted on the board before it was played.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, game::Game, square::Square};
+    ///
+    /// let mut game = Game::new();
+    /// game.play_move(ChessMove::new(Square::E2, Square::E4)).unwrap();
+    /// game.play_move(ChessMove::new(Square::G8, Square::F6)).unwrap();
+    ///
+    /// assert_eq!(game.san_history(), vec!["e4", "Nf6"]);
+    /// ```
+    pub fn san_history(&self) -> Vec<String> {
+        let mut board = self.board;
+        let mut san_history = vec![];
+
+        for event in self.history.iter().rev() {
+            if let Event::Move((mv, metadata, unmake_data)) = event {
+                board
+                    .unmake_move(*mv, *metadata, *unmake_data)
+                    .expect("history moves can be unmade");
+                san_history.push(board.to_san(*mv));
+            }
+        }
+
+        san_history.reverse();
+        san_history
+    }
+
     /// Resign the game provided a [`Color`] that resigns.
     pub fn resign(&mut self, color: Color) -> Result<(), Error> {
         if let Some(event) = self.history.last()
@@ -154,6 +220,96 @@ impl Game {
         Ok(())
     }
 
+    /// Agree to a draw, ending the game.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the draw was successfully recorded.
+    /// - `Err(Error)` if the game has already ended.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::game::{Event, Game};
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// let _ = game.draw_by_agreement();
+    ///
+    /// assert_eq!(game.history.last(), Some(&Event::DrawByAgreement));
+    /// ```
+    pub fn draw_by_agreement(&mut self) -> Result<(), Error> {
+        if let Some(event) = self.history.last()
+            && event.is_gameending()
+        {
+            return Err(Error::GameEnded);
+        }
+
+        self.history.push(Event::DrawByAgreement);
+
+        Ok(())
+    }
+
+    /// Adjudicate the game as a draw if it has gone on for more than `max_plies`.
+    ///
+    /// # Parameters
+    /// - `max_plies`: The maximum number of plies the game is allowed to last.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the game was adjudicated as a draw.
+    /// - `Ok(false)` if the game is still within the ply limit.
+    /// - `Err(Error)` if the game has already ended.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, game::{Event, Game}, square::Square};
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// let _ = game.play_move(ChessMove::new(Square::E2, Square::E4));
+    /// let _ = game.play_move(ChessMove::new(Square::E7, Square::E5));
+    /// assert_eq!(game.adjudicate(2), Ok(false));
+    ///
+    /// let _ = game.play_move(ChessMove::new(Square::G1, Square::F3));
+    /// assert_eq!(game.adjudicate(2), Ok(true));
+    ///
+    /// assert_eq!(game.history.last(), Some(&Event::DrawByAdjudication));
+    /// ```
+    pub fn adjudicate(&mut self, max_plies: usize) -> Result<bool, Error> {
+        if let Some(event) = self.history.last()
+            && event.is_gameending()
+        {
+            return Err(Error::GameEnded);
+        }
+
+        if self.ply <= max_plies {
+            return Ok(false);
+        }
+
+        self.history.push(Event::DrawByAdjudication);
+
+        Ok(true)
+    }
+
+    /// Get the [`GameResult`] of the [`Game`], or `None` if the game hasn't ended.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{color::Color, game::{Event, Game, GameResult}};
+    ///
+    /// let mut game = Game::new();
+    /// assert_eq!(game.result(), None);
+    ///
+    /// let _ = game.resign(Color::Black);
+    /// assert_eq!(game.result(), Some(GameResult::Win(Color::White)));
+    /// ```
+    pub fn result(&self) -> Option<GameResult> {
+        match self.history.last()? {
+            Event::Move(_) => None,
+            Event::Checkmate => Some(GameResult::Win(!self.board.side_to_move)),
+            Event::Resignation(color) | Event::Timeout(color) => Some(GameResult::Win(!*color)),
+            event => Some(GameResult::Draw(*event)),
+        }
+    }
+
     /// Play a move on the current [`Board`].
     ///
     /// # Parameters
@@ -225,11 +381,8 @@ impl Game {
 
         self.make_move(mv)?;
 
-        if let Some(Event::Move((_, metadata, _))) = self
-            .history
-            .iter()
-            .filter(|x| matches!(x, Event::Move(_)))
-            .next_back()
+        if let Some(Event::Move((_, metadata, _))) =
+            self.history.iter().rfind(|x| matches!(x, Event::Move(_)))
         {
             match metadata {
                 MoveMetaData::Capture(..)
@@ -0,0 +1,29 @@
+use std::io::Write;
+use std::{fs::File, sync::Mutex};
+
+use crate::square::{SQUARES, Square};
+
+static CENTER_DISTANCE: Mutex<[u8; 64]> = Mutex::new([0; 64]);
+
+pub fn generate_center_distance() {
+    let mut center_distance = CENTER_DISTANCE.lock().unwrap();
+
+    for square in SQUARES.iter() {
+        center_distance[square.to_index()] = [Square::D4, Square::D5, Square::E4, Square::E5]
+            .iter()
+            .map(|center| square.king_distance(*center))
+            .min()
+            .unwrap();
+    }
+}
+
+pub fn write_center_distance(f: &mut File) {
+    generate_center_distance();
+
+    writeln!(
+        f,
+        "pub const CENTER_DISTANCE: [u8; 64] = {:?};",
+        CENTER_DISTANCE.lock().unwrap(),
+    )
+    .unwrap();
+}
@@ -7,6 +7,7 @@ use crate::{
 };
 
 static KNIGHT_MOVES: Mutex<[BitBoard; 64]> = Mutex::new([EMPTY; 64]);
+static KNIGHT_DISTANCE: Mutex<[[u8; 64]; 64]> = Mutex::new([[0; 64]; 64]);
 
 pub fn generate_knight_moves() {
     for src in SQUARES.iter() {
@@ -34,8 +35,39 @@ pub fn generate_knight_moves() {
     }
 }
 
+pub fn generate_knight_distance() {
+    let knight_moves = KNIGHT_MOVES.lock().unwrap();
+    let mut knight_distance = KNIGHT_DISTANCE.lock().unwrap();
+
+    for src in SQUARES.iter() {
+        let distances = &mut knight_distance[src.to_index()];
+
+        let mut visited = BitBoard::from_square(*src);
+        let mut frontier = visited;
+        let mut distance = 0;
+
+        while frontier != EMPTY {
+            distance += 1;
+
+            let mut next = EMPTY;
+            for square in frontier {
+                next |= knight_moves[square.to_index()];
+            }
+            next &= !visited;
+
+            for square in next {
+                distances[square.to_index()] = distance;
+            }
+
+            visited |= next;
+            frontier = next;
+        }
+    }
+}
+
 pub fn write_knight_moves(f: &mut File) {
     generate_knight_moves();
+    generate_knight_distance();
 
     writeln!(
         f,
@@ -43,4 +75,11 @@ pub fn write_knight_moves(f: &mut File) {
         KNIGHT_MOVES.lock().unwrap(),
     )
     .unwrap();
+
+    writeln!(
+        f,
+        "pub static KNIGHT_DISTANCE: [[u8; 64]; 64] = {:?};",
+        KNIGHT_DISTANCE.lock().unwrap(),
+    )
+    .unwrap();
 }
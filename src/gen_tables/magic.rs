@@ -9,6 +9,7 @@ use rand_chacha::{
 
 use crate::{
     bitboard::{BitBoard, EMPTY},
+    direction::Direction,
     square::{SQUARES, Square},
 };
 
@@ -26,37 +27,19 @@ struct Magic {
     pub offset: u32,
 }
 
-fn rook_directions() -> [fn(Square) -> Option<Square>; 4] {
-    fn north(square: Square) -> Option<Square> {
-        square.up()
-    }
-    fn east(square: Square) -> Option<Square> {
-        square.right()
-    }
-    fn south(square: Square) -> Option<Square> {
-        square.down()
-    }
-    fn west(square: Square) -> Option<Square> {
-        square.left()
-    }
-    [north, east, south, west]
-}
+const ROOK_DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
 
-fn bishop_directions() -> [fn(Square) -> Option<Square>; 4] {
-    fn north_east(square: Square) -> Option<Square> {
-        square.up().and_then(|square| square.right())
-    }
-    fn south_east(square: Square) -> Option<Square> {
-        square.down().and_then(|square| square.right())
-    }
-    fn south_west(square: Square) -> Option<Square> {
-        square.down().and_then(|square| square.left())
-    }
-    fn north_west(square: Square) -> Option<Square> {
-        square.up().and_then(|square| square.left())
-    }
-    [north_east, south_east, south_west, north_west]
-}
+const BISHOP_DIRECTIONS: [Direction; 4] = [
+    Direction::NorthEast,
+    Direction::SouthEast,
+    Direction::SouthWest,
+    Direction::NorthWest,
+];
 
 fn flatten_data(data: ([Magic; 64], [Vec<BitBoard>; 64])) -> ([Magic; 64], Vec<BitBoard>) {
     let (magic_array, moves_array) = data;
@@ -165,11 +148,11 @@ fn generate_bishop_magics_and_moves() -> ([Magic; 64], Vec<BitBoard>) {
 pub(crate) fn generate_bishop_moves(square: Square, blockers: BitBoard) -> BitBoard {
     let mut moves = BitBoard(0);
 
-    for mv in bishop_directions() {
-        let mut next = mv(square);
+    for direction in BISHOP_DIRECTIONS {
+        let mut next = square.step(direction);
         while let Some(current) = next {
             moves |= BitBoard::from_square(current);
-            next = mv(current);
+            next = current.step(direction);
 
             if BitBoard::from_square(current) & blockers != EMPTY {
                 break;
@@ -214,11 +197,11 @@ fn generate_rook_moves_and_magics() -> ([Magic; 64], Vec<BitBoard>) {
 pub(crate) fn generate_rook_moves(square: Square, blockers: BitBoard) -> BitBoard {
     let mut moves = BitBoard(0);
 
-    for mv in rook_directions() {
-        let mut next = mv(square);
+    for direction in ROOK_DIRECTIONS {
+        let mut next = square.step(direction);
         while let Some(current) = next {
             moves |= BitBoard::from_square(current);
-            next = mv(current);
+            next = current.step(direction);
 
             if BitBoard::from_square(current) & blockers != EMPTY {
                 break;
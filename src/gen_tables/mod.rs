@@ -1,11 +1,11 @@
 mod between;
+mod center;
 mod files;
 mod helpers;
 mod king;
 mod knight;
 mod magic;
 mod pawn;
-#[cfg(feature = "bmi2")]
 mod pext;
 mod ranks;
 mod rays;
@@ -13,13 +13,12 @@ mod tangent;
 mod zobrist;
 
 pub use self::between::write_between;
+pub use self::center::write_center_distance;
 pub use self::files::write_files;
 pub use self::king::write_king_moves;
 pub use self::knight::write_knight_moves;
-#[cfg(not(feature = "bmi2"))]
 pub use self::magic::{write_bishop_moves, write_rook_moves};
 pub use self::pawn::{write_passed_pawn, write_pawn_attacks, write_pawn_moves};
-#[cfg(feature = "bmi2")]
 pub use self::pext::{write_bishop_pext, write_rook_pext};
 pub use self::ranks::{write_backward_ranks, write_forward_ranks, write_ranks};
 pub use self::rays::{write_bishop_rays, write_rook_rays};
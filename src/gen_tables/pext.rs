@@ -1,5 +1,5 @@
+use std::fs::File;
 use std::io::Write;
-use std::{arch::x86_64::_pext_u64, fs::File};
 
 use crate::{
     bitboard::{BitBoard, EMPTY},
@@ -16,8 +16,22 @@ struct PextEntry {
     offset: u32,
 }
 
+// A software `pext`, so the tables don't depend on the host supporting BMI2.
 fn pext_index(mask: BitBoard, blockers: BitBoard) -> usize {
-    unsafe { _pext_u64(blockers.0, mask.0) as usize }
+    let mut index = 0;
+    let mut mask = mask.0;
+    let mut bit = 1;
+
+    while mask != 0 {
+        if blockers.0 & mask & mask.wrapping_neg() != 0 {
+            index |= bit;
+        }
+
+        mask &= mask - 1;
+        bit <<= 1;
+    }
+
+    index
 }
 
 fn generate_table(piece: MagicPiece, square: Square) -> (BitBoard, Vec<BitBoard>) {
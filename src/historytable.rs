@@ -0,0 +1,115 @@
+use crate::{chess_move::ChessMove, color::Color};
+
+/// The maximum absolute score of a move in the [`HistoryTable`].
+pub const MAX_HISTORY: i32 = 16384;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash)]
+pub struct HistoryTable {
+    table: Vec<i32>,
+}
+
+impl Default for HistoryTable {
+    fn default() -> Self {
+        HistoryTable::new()
+    }
+}
+
+impl HistoryTable {
+    /// Create a new empty [`HistoryTable`].
+    pub fn new() -> HistoryTable {
+        HistoryTable {
+            table: vec![0; 2 * 64 * 64],
+        }
+    }
+
+    fn index(color: Color, mv: ChessMove) -> usize {
+        let (from, to) = mv.get_move();
+        (color.to_index() * 64 + from.to_index()) * 64 + to.to_index()
+    }
+
+    /// Update the score of a [`ChessMove`] for the given [`Color`] by `bonus`, which may be negative.
+    ///
+    /// Uses history gravity: the closer the score is to [`MAX_HISTORY`], the less a bonus moves it,
+    /// so scores stay within `-MAX_HISTORY..=MAX_HISTORY`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, color::Color, historytable::HistoryTable, square::Square};
+    ///
+    /// let mut history = HistoryTable::new();
+    /// let mv = ChessMove::new(Square::G1, Square::F3);
+    ///
+    /// history.update(Color::White, mv, 400);
+    /// let score = history.get(Color::White, mv);
+    /// assert!(score > 0);
+    ///
+    /// history.update(Color::White, mv, 400);
+    /// assert!(history.get(Color::White, mv) > score);
+    ///
+    /// assert_eq!(history.get(Color::Black, mv), 0);
+    /// ```
+    pub fn update(&mut self, color: Color, mv: ChessMove, bonus: i32) {
+        let bonus = bonus.clamp(-MAX_HISTORY, MAX_HISTORY);
+        let entry = &mut self.table[Self::index(color, mv)];
+
+        *entry += bonus - *entry * bonus.abs() / MAX_HISTORY;
+    }
+
+    /// Get the score of a [`ChessMove`] for the given [`Color`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, color::Color, historytable::HistoryTable, square::Square};
+    ///
+    /// let mut history = HistoryTable::new();
+    /// let mv = ChessMove::new(Square::E7, Square::E5);
+    ///
+    /// history.update(Color::Black, mv, -200);
+    ///
+    /// assert_eq!(history.get(Color::Black, mv), -200);
+    /// ```
+    pub fn get(&self, color: Color, mv: ChessMove) -> i32 {
+        self.table[Self::index(color, mv)]
+    }
+
+    /// Age the [`HistoryTable`] by halving every score, e.g. between searches.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, color::Color, historytable::HistoryTable, square::Square};
+    ///
+    /// let mut history = HistoryTable::new();
+    /// let mv = ChessMove::new(Square::G1, Square::F3);
+    ///
+    /// history.update(Color::White, mv, 400);
+    /// history.update(Color::White, mv, 400);
+    /// let score = history.get(Color::White, mv);
+    ///
+    /// history.decay();
+    /// assert!(history.get(Color::White, mv) < score);
+    /// assert_eq!(history.get(Color::White, mv), score / 2);
+    /// ```
+    pub fn decay(&mut self) {
+        for entry in self.table.iter_mut() {
+            *entry /= 2;
+        }
+    }
+
+    /// Clear the [`HistoryTable`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, color::Color, historytable::HistoryTable, square::Square};
+    ///
+    /// let mut history = HistoryTable::new();
+    /// let mv = ChessMove::new(Square::G1, Square::F3);
+    ///
+    /// history.update(Color::White, mv, 400);
+    /// history.clear();
+    ///
+    /// assert_eq!(history.get(Color::White, mv), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.table.fill(0);
+    }
+}
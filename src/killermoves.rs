@@ -0,0 +1,83 @@
+use crate::chess_move::ChessMove;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Default)]
+pub struct KillerMoves {
+    killers: Vec<[Option<ChessMove>; 2]>,
+}
+
+impl KillerMoves {
+    /// Create a new empty [`KillerMoves`] table.
+    pub fn new() -> KillerMoves {
+        KillerMoves::default()
+    }
+
+    /// Create a new empty [`KillerMoves`] table with room for `max_ply` plies.
+    pub fn with_capacity(max_ply: usize) -> KillerMoves {
+        KillerMoves {
+            killers: vec![[None; 2]; max_ply],
+        }
+    }
+
+    /// Store a killer [`ChessMove`] at the given `ply`, shifting the previous killer to the second
+    /// slot and evicting the oldest one.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, killermoves::KillerMoves, square::Square};
+    ///
+    /// let mut killers = KillerMoves::new();
+    ///
+    /// let first = ChessMove::new(Square::G1, Square::F3);
+    /// let second = ChessMove::new(Square::B1, Square::C3);
+    /// let third = ChessMove::new(Square::E2, Square::E4);
+    ///
+    /// killers.store(3, first);
+    /// killers.store(3, second);
+    /// assert!(killers.contains(3, first));
+    /// assert!(killers.contains(3, second));
+    ///
+    /// killers.store(3, third);
+    /// assert!(!killers.contains(3, first));
+    /// assert_eq!(killers.get(3), [Some(third), Some(second)]);
+    /// ```
+    pub fn store(&mut self, ply: usize, mv: ChessMove) {
+        if ply >= self.killers.len() {
+            self.killers.resize(ply + 1, [None; 2]);
+        }
+
+        let killers = &mut self.killers[ply];
+
+        if killers[0] != Some(mv) {
+            killers[1] = killers[0];
+            killers[0] = Some(mv);
+        }
+    }
+
+    /// Get both killer moves at the given `ply`, most recent first.
+    pub fn get(&self, ply: usize) -> [Option<ChessMove>; 2] {
+        self.killers.get(ply).copied().unwrap_or([None; 2])
+    }
+
+    /// Check if a [`ChessMove`] is a killer move at the given `ply`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{chess_move::ChessMove, killermoves::KillerMoves, square::Square};
+    ///
+    /// let mut killers = KillerMoves::new();
+    /// let mv = ChessMove::new(Square::G1, Square::F3);
+    ///
+    /// killers.store(2, mv);
+    ///
+    /// assert!(killers.contains(2, mv));
+    /// assert!(!killers.contains(1, mv));
+    /// ```
+    pub fn contains(&self, ply: usize, mv: ChessMove) -> bool {
+        self.get(ply).contains(&Some(mv))
+    }
+
+    /// Clear the [`KillerMoves`] table.
+    pub fn clear(&mut self) {
+        self.killers.fill([None; 2]);
+    }
+}
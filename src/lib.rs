@@ -22,13 +22,23 @@ pub mod board;
 pub mod castling_rights;
 pub mod chess_move;
 pub mod color;
+pub mod direction;
 pub mod error;
+pub mod evaluation;
 pub mod file;
 #[cfg(feature = "game")]
 pub mod game;
+pub mod historytable;
+pub mod killermoves;
 pub mod magic;
+pub mod movegen;
 pub mod piece;
 pub mod rank;
+pub mod repetitiontable;
+pub mod score;
+#[cfg(feature = "search")]
+pub mod search;
 pub mod square;
+pub mod tablebase;
 pub mod transpositiontable;
 pub mod uci;
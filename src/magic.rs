@@ -1,4 +1,4 @@
-#[cfg(feature = "bmi2")]
+#[cfg(use_pext)]
 use std::arch::x86_64::_pext_u64;
 
 use crate::{
@@ -9,7 +9,7 @@ use crate::{
 include!(concat!(env!("OUT_DIR"), "/tables.rs"));
 include!(concat!(env!("OUT_DIR"), "/magic_tables.rs"));
 
-#[cfg(not(feature = "bmi2"))]
+#[cfg(not(use_pext))]
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Magic {
     pub mask: BitBoard,
@@ -18,7 +18,7 @@ pub struct Magic {
     pub offset: u32,
 }
 
-#[cfg(feature = "bmi2")]
+#[cfg(use_pext)]
 #[derive(Debug, Clone, Copy)]
 pub struct PextEntry {
     mask: BitBoard,
@@ -26,7 +26,7 @@ pub struct PextEntry {
 }
 
 #[inline(always)]
-#[cfg(not(feature = "bmi2"))]
+#[cfg(not(use_pext))]
 pub fn magic_index(magic: Magic, blockers: BitBoard) -> usize {
     let blockers = blockers & magic.mask;
     let hash = blockers.0.wrapping_mul(magic.magic);
@@ -35,11 +35,29 @@ pub fn magic_index(magic: Magic, blockers: BitBoard) -> usize {
 }
 
 #[inline(always)]
-#[cfg(feature = "bmi2")]
+#[cfg(use_pext)]
 pub fn pext_index(entry: PextEntry, blockers: BitBoard) -> usize {
     unsafe { entry.offset as usize + _pext_u64(blockers.0, entry.mask.0) as usize }
 }
 
+/// Return the total size in bytes of the sliding piece lookup tables, including the magics (or
+/// PEXT entries).
+///
+/// # Example
+/// ```
+/// use chessframe::magic::magic_table_bytes;
+///
+/// assert!(magic_table_bytes() > 0);
+/// ```
+pub fn magic_table_bytes() -> usize {
+    #[cfg(not(use_pext))]
+    let entries = size_of_val(&BISHOP_MAGICS) + size_of_val(&ROOK_MAGICS);
+    #[cfg(use_pext)]
+    let entries = size_of_val(&BISHOP_PEXT_ENTRIES) + size_of_val(&ROOK_PEXT_ENTRIES);
+
+    entries + size_of_val(BISHOP_MOVES_TABLE) + size_of_val(ROOK_MOVES_TABLE)
+}
+
 #[inline]
 pub fn get_pawn_moves(square: Square, color: Color) -> BitBoard {
     unsafe {
@@ -64,7 +82,7 @@ pub fn get_knight_moves(square: Square) -> BitBoard {
 }
 
 #[inline(always)]
-#[cfg(not(feature = "bmi2"))]
+#[cfg(not(use_pext))]
 pub fn get_bishop_moves(square: Square, blockers: BitBoard) -> BitBoard {
     unsafe {
         let magic = BISHOP_MAGICS.get_unchecked(square.to_index());
@@ -74,7 +92,7 @@ pub fn get_bishop_moves(square: Square, blockers: BitBoard) -> BitBoard {
 }
 
 #[inline(always)]
-#[cfg(feature = "bmi2")]
+#[cfg(use_pext)]
 pub fn get_bishop_moves(square: Square, blockers: BitBoard) -> BitBoard {
     unsafe {
         let entry = BISHOP_PEXT_ENTRIES.get_unchecked(square.to_index());
@@ -84,7 +102,7 @@ pub fn get_bishop_moves(square: Square, blockers: BitBoard) -> BitBoard {
 }
 
 #[inline(always)]
-#[cfg(not(feature = "bmi2"))]
+#[cfg(not(use_pext))]
 pub fn get_rook_moves(square: Square, blockers: BitBoard) -> BitBoard {
     unsafe {
         let magic = ROOK_MAGICS.get_unchecked(square.to_index());
@@ -94,7 +112,7 @@ pub fn get_rook_moves(square: Square, blockers: BitBoard) -> BitBoard {
 }
 
 #[inline(always)]
-#[cfg(feature = "bmi2")]
+#[cfg(use_pext)]
 pub fn get_rook_moves(square: Square, blockers: BitBoard) -> BitBoard {
     unsafe {
         let entry = ROOK_PEXT_ENTRIES.get_unchecked(square.to_index());
@@ -156,6 +174,46 @@ pub fn get_tangent(start: Square, end: Square) -> BitBoard {
     }
 }
 
+#[inline]
+pub fn get_knight_distance(start: Square, end: Square) -> u8 {
+    unsafe {
+        *KNIGHT_DISTANCE
+            .get_unchecked(start.to_index())
+            .get_unchecked(end.to_index())
+    }
+}
+
+/// Return the Chebyshev distance from `square` to the closest of the four center squares.
+///
+/// # Example
+/// ```
+/// use chessframe::{magic::center_distance, square::Square};
+///
+/// assert_eq!(center_distance(Square::A1), 3);
+/// assert_eq!(center_distance(Square::E4), 0);
+/// ```
+#[inline]
+pub fn center_distance(square: Square) -> u8 {
+    unsafe { *CENTER_DISTANCE.get_unchecked(square.to_index()) }
+}
+
+// `square.rs` is also compiled into the build script, which can't see the generated tables.
+impl Square {
+    /// Return the minimum number of knight moves needed to get from this `Square` to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert_eq!(Square::B1.knight_distance(Square::C3), 1);
+    /// assert_eq!(Square::A1.knight_distance(Square::H8), 6);
+    /// ```
+    #[inline]
+    pub fn knight_distance(&self, other: Square) -> u8 {
+        get_knight_distance(*self, other)
+    }
+}
+
 pub struct Zobrist;
 
 impl Zobrist {
@@ -0,0 +1,149 @@
+use std::{collections::VecDeque, vec::IntoIter};
+
+use crate::{board::Board, chess_move::ChessMove, piece::Piece};
+
+/// An iterator over the legal [`ChessMove`]'s of a [`Board`].
+///
+/// [`MoveGen::new`] yields the moves in the order of [`Board::generate_legal_into`], while
+/// [`MoveGen::with_see`] orders them for a search using the static exchange evaluation.
+///
+/// # Example
+/// ```
+/// use chessframe::{board::Board, movegen::MoveGen};
+///
+/// let board = Board::default();
+///
+/// assert_eq!(MoveGen::new(&board).count(), 20);
+/// assert_eq!(MoveGen::new(&board).len(), 20);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoveGen {
+    moves: IntoIter<ChessMove>,
+    see: Option<SeeOrdering>,
+}
+
+/// The captures of a [`MoveGen`] created with [`MoveGen::with_see`] that haven't been yielded yet.
+#[derive(Debug, Clone)]
+struct SeeOrdering {
+    board: Board,
+    captures: Vec<(ChessMove, i32)>,
+    losing_captures: VecDeque<ChessMove>,
+}
+
+impl MoveGen {
+    /// Create a [`MoveGen`] yielding the legal moves of the [`Board`].
+    pub fn new(board: &Board) -> MoveGen {
+        let mut moves = Vec::with_capacity(96);
+        board.generate_legal_into(&mut moves);
+
+        MoveGen {
+            moves: moves.into_iter(),
+            see: None,
+        }
+    }
+
+    /// Create a [`MoveGen`] yielding the legal moves of the [`Board`] with the captures ordered by
+    /// the static exchange evaluation, see [`Board::see_ge`]: first the captures not losing
+    /// material, then the quiet moves in generation order and last the captures losing material.
+    /// Captures are tried most valuable captured piece first, and the exchange is only evaluated
+    /// once a capture is about to be yielded.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, movegen::MoveGen, square::Square};
+    ///
+    /// // Taking the rook with the pawn wins material, taking the defended rook with the queen
+    /// // loses the queen.
+    /// let board = Board::from_fen("4k3/8/2p5/1p1r4/2P5/8/8/3QK3 w - - 0 1");
+    /// let moves: Vec<ChessMove> = MoveGen::with_see(&board).collect();
+    ///
+    /// assert_eq!(moves.first(), Some(&ChessMove::new(Square::C4, Square::D5)));
+    /// assert_eq!(moves.last(), Some(&ChessMove::new(Square::D1, Square::D5)));
+    /// ```
+    pub fn with_see(board: &Board) -> MoveGen {
+        let mut moves = Vec::with_capacity(96);
+        board.generate_legal_into(&mut moves);
+
+        let mut captures = Vec::new();
+        moves.retain(|mv| {
+            let (from, to) = mv.get_move();
+
+            let captured = if board.color_on(to) == Some(!board.side_to_move) {
+                board.get_piece(to)
+            } else if board.get_piece(from) == Some(Piece::Pawn)
+                && Some(to) == board.en_passant_square()
+            {
+                Some(Piece::Pawn)
+            } else {
+                None
+            };
+
+            match captured {
+                Some(captured) => {
+                    captures.push((*mv, captured.value()));
+                    false
+                }
+                None => true,
+            }
+        });
+
+        MoveGen {
+            moves: moves.into_iter(),
+            see: Some(SeeOrdering {
+                board: *board,
+                captures,
+                losing_captures: VecDeque::new(),
+            }),
+        }
+    }
+}
+
+impl SeeOrdering {
+    /// Get the next capture not losing material, setting aside the losing ones on the way.
+    fn next_capture(&mut self) -> Option<ChessMove> {
+        while let Some((index, _)) = self
+            .captures
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (_, value))| -value)
+        {
+            let (mv, _) = self.captures.remove(index);
+
+            if self.board.see_ge(mv, 0) {
+                return Some(mv);
+            }
+
+            self.losing_captures.push_back(mv);
+        }
+
+        None
+    }
+
+    fn len(&self) -> usize {
+        self.captures.len() + self.losing_captures.len()
+    }
+}
+
+impl Iterator for MoveGen {
+    type Item = ChessMove;
+
+    fn next(&mut self) -> Option<ChessMove> {
+        if let Some(see) = &mut self.see
+            && let Some(mv) = see.next_capture()
+        {
+            return Some(mv);
+        }
+
+        self.moves
+            .next()
+            .or_else(|| self.see.as_mut()?.losing_captures.pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.moves.len() + self.see.as_ref().map_or(0, SeeOrdering::len);
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for MoveGen {}
@@ -1,7 +1,9 @@
-use crate::color::Color;
+use std::str::FromStr;
+
+use crate::{color::Color, error::Error};
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Piece {
     Pawn,
     Knight,
@@ -48,6 +50,32 @@ impl From<char> for Piece {
     }
 }
 
+impl FromStr for Piece {
+    type Err = Error;
+
+    /// Parse a [`Piece`] from its letter or its name, ignoring case.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{error::Error, piece::Piece};
+    ///
+    /// assert_eq!("N".parse(), Ok(Piece::Knight));
+    /// assert_eq!("knight".parse(), Ok(Piece::Knight));
+    /// assert_eq!("x".parse::<Piece>(), Err(Error::InvalidPiece));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "p" | "pawn" => Ok(Piece::Pawn),
+            "n" | "knight" => Ok(Piece::Knight),
+            "b" | "bishop" => Ok(Piece::Bishop),
+            "r" | "rook" => Ok(Piece::Rook),
+            "q" | "queen" => Ok(Piece::Queen),
+            "k" | "king" => Ok(Piece::King),
+            _ => Err(Error::InvalidPiece),
+        }
+    }
+}
+
 #[allow(dead_code)]
 impl Piece {
     pub fn to_fen(self) -> char {
@@ -65,6 +93,18 @@ impl Piece {
         self as usize
     }
 
+    /// Get the material value of this `Piece` in centipawns. The king has no material value.
+    pub fn value(self) -> i32 {
+        match self {
+            Piece::Pawn => 100,
+            Piece::Knight => 300,
+            Piece::Bishop => 325,
+            Piece::Rook => 500,
+            Piece::Queen => 900,
+            Piece::King => 0,
+        }
+    }
+
     pub fn piece_index(&self, color: Color) -> usize {
         let offset = match color {
             Color::White => 0,
@@ -0,0 +1,110 @@
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash)]
+pub struct RepetitionTable {
+    hashes: Vec<u64>,
+    head: usize,
+    len: usize,
+}
+
+impl Default for RepetitionTable {
+    fn default() -> Self {
+        RepetitionTable::with_capacity(1024)
+    }
+}
+
+impl RepetitionTable {
+    /// Create a new [`RepetitionTable`] remembering the last `capacity` hashes.
+    pub fn with_capacity(capacity: usize) -> RepetitionTable {
+        RepetitionTable {
+            hashes: vec![0; capacity.max(1)],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Get the number of hashes currently in the [`RepetitionTable`].
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the [`RepetitionTable`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Push the hash of a position onto the [`RepetitionTable`], overwriting the oldest hash if
+    /// it is full.
+    pub fn push(&mut self, hash: u64) {
+        self.hashes[self.head] = hash;
+        self.head = (self.head + 1) % self.hashes.len();
+        self.len = (self.len + 1).min(self.hashes.len());
+    }
+
+    /// Pop the most recent hash off the [`RepetitionTable`].
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::repetitiontable::RepetitionTable;
+    ///
+    /// let mut repetitions = RepetitionTable::with_capacity(2);
+    ///
+    /// repetitions.push(1);
+    /// repetitions.push(2);
+    /// repetitions.push(3);
+    ///
+    /// assert_eq!(repetitions.pop(), Some(3));
+    /// assert_eq!(repetitions.pop(), Some(2));
+    /// assert_eq!(repetitions.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.head = (self.head + self.hashes.len() - 1) % self.hashes.len();
+        self.len -= 1;
+
+        Some(self.hashes[self.head])
+    }
+
+    /// Check if the position with the given `hash` occurred before since the last irreversible
+    /// move, i.e. within the last `halfmove_clock` plies. Only positions with the same side to
+    /// move are compared.
+    ///
+    /// The [`RepetitionTable`] should contain the hashes of the positions leading up to the
+    /// current one, but not the current position itself.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{board::Board, chess_move::ChessMove, repetitiontable::RepetitionTable, square::Square};
+    ///
+    /// let mut board = Board::default();
+    /// let mut repetitions = RepetitionTable::with_capacity(64);
+    ///
+    /// for (from, to) in [
+    ///     (Square::G1, Square::F3),
+    ///     (Square::G8, Square::F6),
+    ///     (Square::F3, Square::G1),
+    ///     (Square::F6, Square::G8),
+    /// ] {
+    ///     assert!(!repetitions.is_repetition(board.hash(), board.half_moves as usize));
+    ///
+    ///     repetitions.push(board.hash());
+    ///     board.make_move(ChessMove::new(from, to)).unwrap();
+    /// }
+    ///
+    /// assert!(repetitions.is_repetition(board.hash(), board.half_moves as usize));
+    /// ```
+    pub fn is_repetition(&self, hash: u64, halfmove_clock: usize) -> bool {
+        let capacity = self.hashes.len();
+
+        (2..=halfmove_clock.min(self.len))
+            .step_by(2)
+            .any(|ply| self.hashes[(self.head + capacity - ply) % capacity] == hash)
+    }
+
+    /// Clear the [`RepetitionTable`].
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+}
@@ -0,0 +1,91 @@
+/// The score of a draw.
+pub const DRAW_SCORE: i32 = 0;
+
+/// The score of delivering checkmate right away, mates further away score lower by one per ply.
+pub const MATE_VALUE: i32 = 1_000_000;
+
+/// The maximum number of plies a search is expected to reach.
+pub const MAX_PLY: usize = 256;
+
+/// The lowest score of a mate found within [`MAX_PLY`] plies, any score at least this high is a
+/// mate score.
+pub const MATE_IN_MAX_PLY: i32 = MATE_VALUE - MAX_PLY as i32;
+
+/// Get the score of delivering checkmate in `ply` plies.
+///
+/// # Example
+/// ```
+/// use chessframe::score::{MATE_VALUE, mate_in};
+///
+/// assert_eq!(mate_in(1), MATE_VALUE - 1);
+/// ```
+#[inline]
+pub fn mate_in(ply: usize) -> i32 {
+    MATE_VALUE - ply as i32
+}
+
+/// Get the score of being checkmated in `ply` plies.
+///
+/// # Example
+/// ```
+/// use chessframe::score::{mate_in, mated_in};
+///
+/// assert_eq!(mated_in(2), -mate_in(2));
+/// ```
+#[inline]
+pub fn mated_in(ply: usize) -> i32 {
+    -MATE_VALUE + ply as i32
+}
+
+/// Check if a score is a mate score, either delivering checkmate or being checkmated.
+///
+/// # Example
+/// ```
+/// use chessframe::score::{is_mate_score, mate_in, mated_in};
+///
+/// assert!(is_mate_score(mate_in(3)));
+/// assert!(is_mate_score(mated_in(4)));
+/// assert!(!is_mate_score(900));
+/// ```
+#[inline]
+pub fn is_mate_score(score: i32) -> bool {
+    score.abs() >= MATE_IN_MAX_PLY
+}
+
+/// Convert a score found `ply` plies from the root to a score to store in a transposition table.
+///
+/// Mate scores are stored relative to the position rather than the root, so they stay correct
+/// when the position is found again at another ply. Use [`score_from_table`] to convert back.
+///
+/// # Example
+/// ```
+/// use chessframe::score::{mate_in, score_from_table, score_to_table};
+///
+/// // A mate in 5 from the root, found 2 plies deep, is a mate in 3 from the position.
+/// assert_eq!(score_to_table(mate_in(5), 2), mate_in(3));
+/// assert_eq!(score_from_table(mate_in(3), 4), mate_in(7));
+/// assert_eq!(score_to_table(150, 2), 150);
+/// ```
+#[inline]
+pub fn score_to_table(score: i32, ply: usize) -> i32 {
+    if score >= MATE_IN_MAX_PLY {
+        score + ply as i32
+    } else if score <= -MATE_IN_MAX_PLY {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+/// Convert a score stored in a transposition table to a score `ply` plies from the root, see
+/// [`score_to_table`].
+#[inline]
+pub fn score_from_table(score: i32, ply: usize) -> i32 {
+    if score >= MATE_IN_MAX_PLY {
+        score - ply as i32
+    } else if score <= -MATE_IN_MAX_PLY {
+        score + ply as i32
+    } else {
+        score
+    }
+}
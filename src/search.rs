@@ -0,0 +1,215 @@
+use std::cmp::Reverse;
+
+use crate::{
+    bitboard::EMPTY,
+    board::Board,
+    chess_move::ChessMove,
+    evaluation::Evaluator,
+    piece::Piece,
+    score::{DRAW_SCORE, MATE_VALUE, mated_in, score_from_table, score_to_table},
+    transpositiontable::TranspositionTable,
+};
+
+const INFINITY: i32 = MATE_VALUE + 1;
+
+/// The number of entries in the [`TranspositionTable`] used by [`negamax`].
+const TABLE_ENTRIES: usize = 1 << 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+enum Bound {
+    #[default]
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+struct TableEntry {
+    score: i32,
+    bound: Bound,
+    best_move: Option<ChessMove>,
+}
+
+struct Searcher<E: Evaluator> {
+    eval: E,
+    table: TranspositionTable<TableEntry>,
+}
+
+/// Search the [`Board`] to a fixed `depth` using alpha-beta negamax, returning the score and the
+/// best move. Returns `None` as the best move if the side to move has no legal moves.
+///
+/// `eval` can be any [`Evaluator`], like [`MaterialEvaluator`] or a closure scoring a position from
+/// the point of view of the side to move. At the end of the search a quiescence search over
+/// captures is done, and moves are ordered by the transposition table and MVV-LVA. Checkmates
+/// score [`MATE_VALUE`] less the number of plies to the mate. Repetitions and the fifty-move rule
+/// aren't taken into account.
+///
+/// [`MaterialEvaluator`]: crate::evaluation::MaterialEvaluator
+///
+/// # Example
+/// ```
+/// use chessframe::{board::Board, chess_move::ChessMove, score::MATE_VALUE, search::negamax, square::Square};
+///
+/// let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1");
+///
+/// let (score, best_move) = negamax(&board, 2, |_: &Board| 0);
+///
+/// assert_eq!(score, MATE_VALUE - 1);
+/// assert_eq!(best_move, Some(ChessMove::new(Square::A1, Square::A8)));
+/// ```
+pub fn negamax(board: &Board, depth: usize, eval: impl Evaluator) -> (i32, Option<ChessMove>) {
+    let mut searcher = Searcher {
+        eval,
+        table: TranspositionTable::with_capacity(TABLE_ENTRIES),
+    };
+
+    searcher.search(board, depth, 0, -INFINITY, INFINITY)
+}
+
+/// Get the MVV-LVA score of a [`ChessMove`], captures of valuable pieces by cheap pieces first.
+fn mvv_lva(board: &Board, mv: ChessMove) -> i32 {
+    let (from, to) = mv.get_move();
+
+    let victim = match board.get_piece(to) {
+        Some(victim) => victim,
+        None if Some(to) == board.en_passant_square() => Piece::Pawn,
+        None => return 0,
+    };
+    let attacker = board.get_piece(from).unwrap_or(Piece::Pawn);
+
+    // Kings have no material value, but are the last piece to capture with.
+    let attacker_value = match attacker {
+        Piece::King => Piece::Queen.value() + 1,
+        _ => attacker.value(),
+    };
+
+    10 * victim.value() - attacker_value + INFINITY
+}
+
+/// Order the moves, the `first` move if any, then captures by MVV-LVA and then the quiet moves.
+fn order_moves(board: &Board, moves: &mut [ChessMove], first: Option<ChessMove>) {
+    moves.sort_by_cached_key(|mv| {
+        if Some(*mv) == first {
+            Reverse(i32::MAX)
+        } else {
+            Reverse(mvv_lva(board, *mv))
+        }
+    });
+}
+
+impl<E: Evaluator> Searcher<E> {
+    fn search(
+        &mut self,
+        board: &Board,
+        depth: usize,
+        ply: usize,
+        mut alpha: i32,
+        beta: i32,
+    ) -> (i32, Option<ChessMove>) {
+        let hash = board.hash();
+        let mut table_move = None;
+
+        if let Some(entry) = self.table.get(hash) {
+            let TableEntry {
+                score,
+                bound,
+                best_move,
+            } = entry.value;
+            let score = score_from_table(score, ply);
+            table_move = best_move;
+
+            if ply > 0 && entry.depth as usize >= depth {
+                match bound {
+                    Bound::Exact => return (score, best_move),
+                    Bound::Lower if score >= beta => return (score, best_move),
+                    Bound::Upper if score <= alpha => return (score, best_move),
+                    _ => {}
+                }
+            }
+        }
+
+        if depth == 0 {
+            return (self.quiescence(board, alpha, beta), None);
+        }
+
+        let original_alpha = alpha;
+        let mut max = -INFINITY;
+        let mut best_move = None;
+
+        let mut moves = board.generate_moves_vec(!EMPTY);
+        order_moves(board, &mut moves, table_move);
+
+        for mv in moves {
+            if let Ok(board) = board.make_move_new(mv) {
+                let score = -self.search(&board, depth - 1, ply + 1, -beta, -alpha).0;
+
+                if score > max {
+                    max = score;
+                    best_move = Some(mv);
+                }
+                if score > alpha {
+                    alpha = score;
+                }
+                if alpha >= beta {
+                    break;
+                }
+            }
+        }
+
+        if best_move.is_none() {
+            let score = if board.in_check() {
+                mated_in(ply)
+            } else {
+                DRAW_SCORE
+            };
+
+            return (score, None);
+        }
+
+        let bound = if max <= original_alpha {
+            Bound::Upper
+        } else if max >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+
+        let entry = TableEntry {
+            score: score_to_table(max, ply),
+            bound,
+            best_move,
+        };
+        let depth = depth.min(u8::MAX as usize) as u8;
+        self.table.store(hash, entry, depth);
+
+        (max, best_move)
+    }
+
+    fn quiescence(&mut self, board: &Board, mut alpha: i32, beta: i32) -> i32 {
+        let evaluation = board.stand_pat(&self.eval);
+        if evaluation >= beta {
+            return evaluation;
+        }
+        if evaluation > alpha {
+            alpha = evaluation;
+        }
+
+        let mut moves = board.generate_moves_vec(board.occupancy(!board.side_to_move));
+        order_moves(board, &mut moves, None);
+
+        for mv in moves {
+            if let Ok(board) = board.make_move_new(mv) {
+                let score = -self.quiescence(&board, -beta, -alpha);
+
+                if score >= beta {
+                    return score;
+                }
+                if score > alpha {
+                    alpha = score;
+                }
+            }
+        }
+
+        alpha
+    }
+}
@@ -1,8 +1,9 @@
 use std::{fmt, str::FromStr};
 
-use crate::{color::Color, error::Error, file::File, rank::Rank};
+use crate::{color::Color, direction::Direction, error::Error, file::File, rank::Rank};
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+/// A square on the board, ordered by index from A1 to H8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Square(u8);
 
 impl FromStr for Square {
@@ -27,6 +28,32 @@ impl FromStr for Square {
     }
 }
 
+impl TryFrom<u8> for Square {
+    type Error = Error;
+
+    /// Convert an index into a `Square`, returning [`Error::InvalidSquare`] if it is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{error::Error, square::Square};
+    ///
+    /// assert_eq!(Square::try_from(0u8), Ok(Square::A1));
+    /// assert_eq!(Square::try_from(64u8), Err(Error::InvalidSquare));
+    /// ```
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Square::try_from(value as usize)
+    }
+}
+
+impl TryFrom<usize> for Square {
+    type Error = Error;
+
+    /// Convert an index into a `Square`, returning [`Error::InvalidSquare`] if it is out of range.
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Square::from_index(value).ok_or(Error::InvalidSquare)
+    }
+}
+
 impl fmt::Display for Square {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let file = self.file().to_index() as u8 + b'a';
@@ -43,6 +70,33 @@ impl Square {
         Square(square % 64)
     }
 
+    /// Create a new `Square` given an index, returning `None` if the index is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert_eq!(Square::from_index(63), Some(Square::H8));
+    /// assert_eq!(Square::from_index(64), None);
+    /// ```
+    #[inline]
+    pub fn from_index(index: usize) -> Option<Square> {
+        if index < 64 {
+            Some(Square(index as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `Square` given an index, without checking that it is in range.
+    ///
+    /// # Safety
+    /// `index` must be less than 64, since the lookup tables are indexed without bounds checks.
+    #[inline]
+    pub unsafe fn from_index_unchecked(index: usize) -> Square {
+        Square(index as u8)
+    }
+
     /// Make a `Square` given its rank and file.
     #[inline]
     pub fn make_square(rank: Rank, file: File) -> Square {
@@ -61,6 +115,91 @@ impl Square {
         File::from_index((self.0 % 8) as usize)
     }
 
+    /// Return the `Rank` given this `Square`. Alias for [`Square::rank`].
+    #[inline]
+    pub fn get_rank(&self) -> Rank {
+        self.rank()
+    }
+
+    /// Return the `File` given this `Square`. Alias for [`Square::file`].
+    #[inline]
+    pub fn get_file(&self) -> File {
+        self.file()
+    }
+
+    /// Check if this `Square` is a light square.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert!(Square::H1.is_light());
+    /// assert!(!Square::A1.is_light());
+    /// ```
+    #[inline]
+    pub fn is_light(&self) -> bool {
+        (self.rank().to_index() + self.file().to_index()) % 2 == 1
+    }
+
+    /// Check if this `Square` is a dark square.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert!(Square::A1.is_dark());
+    /// assert!(!Square::H1.is_dark());
+    /// ```
+    #[inline]
+    pub fn is_dark(&self) -> bool {
+        !self.is_light()
+    }
+
+    /// Return the number of king moves needed to get from this `Square` to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert_eq!(Square::A1.king_distance(Square::H8), 7);
+    /// assert_eq!(Square::E4.king_distance(Square::F6), 2);
+    /// ```
+    #[inline]
+    pub fn king_distance(&self, other: Square) -> u8 {
+        let rank_distance = self.rank().to_index().abs_diff(other.rank().to_index());
+        let file_distance = self.file().to_index().abs_diff(other.file().to_index());
+
+        rank_distance.max(file_distance) as u8
+    }
+
+    /// Mirror this `Square` vertically, swapping the first and eighth ranks.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert_eq!(Square::A1.flip_rank(), Square::A8);
+    /// assert_eq!(Square::E2.flip_rank(), Square::E7);
+    /// ```
+    #[inline]
+    pub fn flip_rank(&self) -> Square {
+        Square(self.0 ^ 56)
+    }
+
+    /// Mirror this `Square` horizontally, swapping the a- and h-files.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::square::Square;
+    ///
+    /// assert_eq!(Square::A1.flip_file(), Square::H1);
+    /// assert_eq!(Square::E2.flip_file(), Square::D2);
+    /// ```
+    #[inline]
+    pub fn flip_file(&self) -> Square {
+        Square(self.0 ^ 7)
+    }
+
     /// If there is a square above this one, return it. Otherwise, return `None`.
     #[inline]
     pub fn up(&self) -> Option<Square> {
@@ -119,6 +258,29 @@ impl Square {
         }
     }
 
+    /// If there is a square in the given [`Direction`], return it. Otherwise, return `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::{direction::Direction, square::Square};
+    ///
+    /// assert_eq!(Square::E4.step(Direction::NorthEast), Some(Square::F5));
+    /// assert_eq!(Square::H4.step(Direction::East), None);
+    /// ```
+    #[inline]
+    pub fn step(&self, direction: Direction) -> Option<Square> {
+        match direction {
+            Direction::North => self.up(),
+            Direction::NorthEast => self.up().and_then(|square| square.right()),
+            Direction::East => self.right(),
+            Direction::SouthEast => self.down().and_then(|square| square.right()),
+            Direction::South => self.down(),
+            Direction::SouthWest => self.down().and_then(|square| square.left()),
+            Direction::West => self.left(),
+            Direction::NorthWest => self.up().and_then(|square| square.left()),
+        }
+    }
+
     /// If there is a square above this one, return it. Otherwise, wrap around.
     #[inline]
     pub fn wrapping_up(&self) -> Square {
@@ -0,0 +1,58 @@
+use crate::board::Board;
+
+/// The win/draw/loss outcome of a position in a tablebase, from the point of view of the side to
+/// move.
+///
+/// A cursed win or a blessed loss is a win or a loss that can't be forced before the fifty-move
+/// rule draws the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Wdl {
+    Loss,
+    BlessedLoss,
+    Draw,
+    CursedWin,
+    Win,
+}
+
+/// Looks up positions with few pieces in an endgame tablebase, like a Syzygy tablebase.
+///
+/// chessframe doesn't read any tablebase files itself, implement this to plug in a prober. Use
+/// [`NoTablebase`] when there is no tablebase.
+pub trait Tablebase {
+    /// The largest number of pieces, kings included, of the positions in the tablebase.
+    fn max_pieces(&self) -> u32;
+
+    /// Get the [`Wdl`] of the [`Board`], or `None` if it isn't in the tablebase.
+    fn probe_wdl(&self, board: &Board) -> Option<Wdl>;
+
+    /// Get the distance to zeroing of the [`Board`], the number of plies to the next capture or
+    /// pawn move in an optimal game, negative when the side to move is losing. Returns `None` if
+    /// the position isn't in the tablebase, which is always the case by default.
+    fn probe_dtz(&self, _board: &Board) -> Option<i32> {
+        None
+    }
+}
+
+/// A [`Tablebase`] without any positions, every probe returns `None`.
+///
+/// # Example
+/// ```
+/// use chessframe::{board::Board, tablebase::{NoTablebase, Tablebase}};
+///
+/// let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+///
+/// assert_eq!(NoTablebase.probe_wdl(&board), None);
+/// assert_eq!(NoTablebase.probe_dtz(&board), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+pub struct NoTablebase;
+
+impl Tablebase for NoTablebase {
+    fn max_pieces(&self) -> u32 {
+        0
+    }
+
+    fn probe_wdl(&self, _board: &Board) -> Option<Wdl> {
+        None
+    }
+}
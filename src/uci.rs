@@ -1,11 +1,49 @@
-use std::{fmt, str::FromStr};
+use std::{cmp::Ordering, fmt, str::FromStr, time::Duration};
+
+use crate::color::Color;
+
+/// The keywords that start a command, the parser skips any tokens before the first of these.
+const COMMAND_KEYWORDS: [&str; 19] = [
+    "uci",
+    "debug",
+    "isready",
+    "setoption",
+    "register",
+    "ucinewgame",
+    "position",
+    "go",
+    "stop",
+    "ponderhit",
+    "quit",
+    "id",
+    "uciok",
+    "readyok",
+    "bestmove",
+    "copyprotection",
+    "registration",
+    "info",
+    "option",
+];
 
 impl FromStr for UciCommand {
-    type Err = &'static str;
+    type Err = String;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         let tokens: Vec<&str> = value.split_whitespace().collect();
 
+        let start = tokens
+            .iter()
+            .position(|token| COMMAND_KEYWORDS.contains(token))
+            .ok_or_else(|| format!("Not a command: {:?}", value))?;
+
+        UciCommand::parse_tokens(&tokens[start..])
+            .map_err(|error| format!("{}: {:?}", error, value))
+    }
+}
+
+impl UciCommand {
+    /// Parse a command from its tokens, starting with the command keyword.
+    fn parse_tokens(tokens: &[&str]) -> Result<UciCommand, &'static str> {
         match tokens.first() {
             Some(&"uci") => Ok(UciCommand::Uci),
             Some(&"debug") => {
@@ -162,6 +200,16 @@ impl FromStr for UciCommand {
                             go.ponder = true;
                             i += 1;
                         }
+                        "searchmoves" => {
+                            let moves = tokens[i + 1..]
+                                .iter()
+                                .take_while(|token| !GO_KEYWORDS.contains(token))
+                                .map(|mv| mv.to_string())
+                                .collect::<Vec<String>>();
+
+                            i += moves.len() + 1;
+                            go.searchmoves = Some(moves);
+                        }
                         _ => i += 1,
                     }
                 }
@@ -279,8 +327,11 @@ impl FromStr for UciCommand {
                             i += 2;
                         }
                         "pv" => {
-                            info.pv = tokens.get(i + 1).map(|s| s.to_string());
-                            i += 2;
+                            // The pv runs to the end of the line.
+                            if i + 1 < tokens.len() {
+                                info.pv = Some(tokens[i + 1..].join(" "));
+                            }
+                            i = tokens.len();
                         }
                         "multipv" => {
                             if let Some(val) = tokens.get(i + 1) {
@@ -383,8 +434,25 @@ impl FromStr for UciCommand {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+/// The keywords that can follow `go`, used to find the end of the `searchmoves` list.
+const GO_KEYWORDS: [&str; 12] = [
+    "searchmoves",
+    "ponder",
+    "wtime",
+    "btime",
+    "winc",
+    "binc",
+    "movestogo",
+    "depth",
+    "nodes",
+    "mate",
+    "movetime",
+    "infinite",
+];
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Default)]
 pub struct Go {
+    pub searchmoves: Option<Vec<String>>,
     pub wtime: Option<usize>,
     pub btime: Option<usize>,
     pub winc: Option<usize>,
@@ -403,6 +471,9 @@ impl fmt::Display for Go {
         write!(f, "score")?;
 
         let fields = [
+            self.searchmoves
+                .as_ref()
+                .map(|v| format!(" searchmoves {}", v.join(" "))),
             self.wtime.map(|v| format!(" wtime {}", v)),
             self.btime.map(|v| format!(" btime {}", v)),
             self.winc.map(|v| format!(" winc {}", v)),
@@ -424,7 +495,83 @@ impl fmt::Display for Go {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Default)]
+/// Decides how long to think about a move, given the limits of a `go` command.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+pub struct TimeControl {
+    /// Time kept back from every move, to cover the delay between the engine and the GUI.
+    pub overhead: Duration,
+    /// The number of moves the remaining time is spread over when `movestogo` isn't given.
+    pub moves_to_go: u32,
+}
+
+impl Default for TimeControl {
+    fn default() -> TimeControl {
+        TimeControl {
+            overhead: Duration::from_millis(50),
+            moves_to_go: 30,
+        }
+    }
+}
+
+impl TimeControl {
+    /// Get the time to spend on the next move for `side`.
+    ///
+    /// An `infinite` search or a `ponder` search, which lasts until `ponderhit`, isn't limited and
+    /// gets [`Duration::MAX`], whatever other limits were sent. A fixed `movetime` is used as is,
+    /// less the overhead. Otherwise the remaining time is split over the moves to go and the
+    /// increment is added, never spending more than the remaining time less the overhead. Without
+    /// any time limit for `side`, [`Duration::MAX`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use chessframe::{color::Color, uci::{Go, TimeControl}};
+    ///
+    /// let time_control = TimeControl::default();
+    /// let go = Go {
+    ///     wtime: Some(60_000),
+    ///     winc: Some(1_000),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(time_control.allocate(&go, Color::White), Duration::from_millis(3_000));
+    /// ```
+    pub fn allocate(&self, go: &Go, side: Color) -> Duration {
+        if go.infinite || go.ponder {
+            return Duration::MAX;
+        }
+
+        if let Some(move_time) = go.move_time {
+            return Duration::from_millis(move_time as u64).saturating_sub(self.overhead);
+        }
+
+        let (time, increment) = match side {
+            Color::White => (go.wtime, go.winc),
+            Color::Black => (go.btime, go.binc),
+        };
+
+        let Some(time) = time else {
+            return Duration::MAX;
+        };
+
+        let time = Duration::from_millis(time as u64);
+        let increment = Duration::from_millis(increment.unwrap_or(0) as u64);
+        let moves_to_go = go
+            .moves_to_go
+            .map_or(self.moves_to_go, |moves| moves as u32)
+            .max(1);
+
+        (time / moves_to_go + increment).min(time.saturating_sub(self.overhead))
+    }
+}
+
+/// The score of an [`Info`], in centipawns or as mate in a number of moves.
+///
+/// Scores are ordered from the point of view of the engine: mates for the engine rank above every
+/// centipawn score with the shortest mate highest, and being mated ranks below every centipawn
+/// score with the shortest mate lowest. A score without either ranks lowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Score {
     pub cp: Option<isize>,
     pub mate: Option<isize>,
@@ -453,6 +600,70 @@ impl fmt::Display for Score {
     }
 }
 
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank()).then_with(|| {
+            (self.cp, self.mate, self.lowerbound, self.upperbound).cmp(&(
+                other.cp,
+                other.mate,
+                other.lowerbound,
+                other.upperbound,
+            ))
+        })
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Score {
+    /// Create a [`Score`] in centipawns.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::uci::Score;
+    ///
+    /// assert_eq!(Score::cp(-35).to_string(), "score cp -35");
+    /// ```
+    pub fn cp(cp: isize) -> Score {
+        Score {
+            cp: Some(cp),
+            ..Default::default()
+        }
+    }
+
+    /// Create a [`Score`] of mate in a number of moves, negative if the engine is getting mated.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::uci::Score;
+    ///
+    /// assert_eq!(Score::mate(3).to_string(), "score mate 3");
+    /// assert!(Score::mate(1) > Score::mate(3));
+    /// assert!(Score::mate(-2) < Score::cp(-900));
+    /// ```
+    pub fn mate(mate: isize) -> Score {
+        Score {
+            mate: Some(mate),
+            ..Default::default()
+        }
+    }
+
+    /// Get a key ordering the [`Score`], see the [`Ord`] implementation. The centipawns are used
+    /// before the mate, like when displaying the [`Score`].
+    fn rank(&self) -> (u8, isize) {
+        match (self.cp, self.mate) {
+            (Some(cp), _) => (2, cp),
+            (None, Some(mate)) if mate > 0 => (3, -mate),
+            (None, Some(mate)) => (1, -mate),
+            (None, None) => (0, 0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Default)]
 pub struct Info {
     pub depth: Option<usize>,
@@ -490,9 +701,10 @@ impl fmt::Display for Info {
             self.hashfull.map(|v| format!(" hashfull {}", v)),
             self.currmove.as_ref().map(|v| format!(" currmove {}", v)),
             self.currmove_number.map(|v| format!(" currmovenumber {}", v)),
+            self.multipv.map(|v| format!(" multipv {}", v)),
             self.score.as_ref().map(|v| format!(" {}", v)),
+            // The pv has to come last, as it runs to the end of the line.
             self.pv.as_ref().map(|v| format!(" pv {}", v)),
-            self.multipv.map(|v| format!(" multipv {}", v)),
         ];
 
         for field in fields.iter().flatten() {
@@ -503,6 +715,151 @@ impl fmt::Display for Info {
     }
 }
 
+impl Info {
+    /// Create an [`InfoBuilder`] to build an [`Info`] without listing every field.
+    ///
+    /// # Example
+    /// ```
+    /// use chessframe::uci::Info;
+    ///
+    /// let info = Info::builder()
+    ///     .depth(5)
+    ///     .score_cp(30)
+    ///     .pv(vec!["e2e4".into(), "e7e5".into()])
+    ///     .build();
+    ///
+    /// assert_eq!(info.to_string(), "info depth 5 score cp 30 pv e2e4 e7e5");
+    /// ```
+    pub fn builder() -> InfoBuilder {
+        InfoBuilder::default()
+    }
+}
+
+/// A builder for [`Info`], see [`Info::builder`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Default)]
+pub struct InfoBuilder {
+    info: Info,
+}
+
+impl InfoBuilder {
+    /// Set the depth.
+    pub fn depth(mut self, depth: usize) -> InfoBuilder {
+        self.info.depth = Some(depth);
+        self
+    }
+
+    /// Set the selective depth.
+    pub fn seldepth(mut self, seldepth: usize) -> InfoBuilder {
+        self.info.seldepth = Some(seldepth);
+        self
+    }
+
+    /// Set the time searched in milliseconds.
+    pub fn time(mut self, time: usize) -> InfoBuilder {
+        self.info.time = Some(time);
+        self
+    }
+
+    /// Set the number of nodes searched.
+    pub fn nodes(mut self, nodes: usize) -> InfoBuilder {
+        self.info.nodes = Some(nodes);
+        self
+    }
+
+    /// Set the multipv line number.
+    pub fn multipv(mut self, multipv: usize) -> InfoBuilder {
+        self.info.multipv = Some(multipv);
+        self
+    }
+
+    /// Set the number of the move currently searched.
+    pub fn currmove_number(mut self, currmove_number: usize) -> InfoBuilder {
+        self.info.currmove_number = Some(currmove_number);
+        self
+    }
+
+    /// Set the hash table usage in permill.
+    pub fn hashfull(mut self, hashfull: usize) -> InfoBuilder {
+        self.info.hashfull = Some(hashfull);
+        self
+    }
+
+    /// Set the nodes searched per second.
+    pub fn nps(mut self, nps: usize) -> InfoBuilder {
+        self.info.nps = Some(nps);
+        self
+    }
+
+    /// Set the tablebase hits.
+    pub fn tbhits(mut self, tbhits: usize) -> InfoBuilder {
+        self.info.tbhits = Some(tbhits);
+        self
+    }
+
+    /// Set the shredderbase hits.
+    pub fn sbhits(mut self, sbhits: usize) -> InfoBuilder {
+        self.info.sbhits = Some(sbhits);
+        self
+    }
+
+    /// Set the CPU usage in permill.
+    pub fn cpuload(mut self, cpuload: usize) -> InfoBuilder {
+        self.info.cpuload = Some(cpuload);
+        self
+    }
+
+    /// Set the principal variation, the moves are joined by spaces.
+    pub fn pv(mut self, pv: Vec<String>) -> InfoBuilder {
+        self.info.pv = Some(pv.join(" "));
+        self
+    }
+
+    /// Set the score.
+    pub fn score(mut self, score: Score) -> InfoBuilder {
+        self.info.score = Some(score);
+        self
+    }
+
+    /// Set the score in centipawns.
+    pub fn score_cp(self, cp: isize) -> InfoBuilder {
+        self.score(Score::cp(cp))
+    }
+
+    /// Set the score as mate in a number of moves.
+    pub fn score_mate(self, mate: isize) -> InfoBuilder {
+        self.score(Score::mate(mate))
+    }
+
+    /// Set the move currently searched.
+    pub fn currmove(mut self, currmove: impl Into<String>) -> InfoBuilder {
+        self.info.currmove = Some(currmove.into());
+        self
+    }
+
+    /// Set a string to send to the GUI.
+    pub fn string(mut self, string: impl Into<String>) -> InfoBuilder {
+        self.info.string = Some(string.into());
+        self
+    }
+
+    /// Set a refutation line.
+    pub fn refutation(mut self, refutation: impl Into<String>) -> InfoBuilder {
+        self.info.refutation = Some(refutation.into());
+        self
+    }
+
+    /// Set the line currently searched.
+    pub fn currline(mut self, currline: impl Into<String>) -> InfoBuilder {
+        self.info.currline = Some(currline.into());
+        self
+    }
+
+    /// Build the [`Info`].
+    pub fn build(self) -> Info {
+        self.info
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash)]
 pub enum UciCommand {
     // Basic commands from GUI to Engine
@@ -563,8 +920,75 @@ pub enum UciCommand {
     Option(String),
 }
 
+/// Format the [`Info`] of every line of a MultiPV search, one per line. Lines without a
+/// `multipv` number are numbered by their position in `lines`, starting from 1.
+///
+/// # Example
+/// ```
+/// use chessframe::uci::{Info, format_multipv};
+///
+/// let lines = [
+///     Info::builder().depth(8).score_cp(40).pv(vec!["e2e4".into(), "e7e5".into()]).build(),
+///     Info::builder().depth(8).score_cp(25).pv(vec!["d2d4".into()]).build(),
+/// ];
+///
+/// assert_eq!(
+///     format_multipv(&lines),
+///     "info depth 8 multipv 1 score cp 40 pv e2e4 e7e5\n\
+///      info depth 8 multipv 2 score cp 25 pv d2d4"
+/// );
+/// ```
+pub fn format_multipv(lines: &[Info]) -> String {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, info)| {
+            let mut info = info.clone();
+            info.multipv.get_or_insert(index + 1);
+            info.to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Get the commands an engine sends in response to `uci`: its `id` followed by `uciok`.
+///
+/// # Example
+/// ```
+/// use chessframe::uci::{UciCommand, respond_uci};
+///
+/// let commands = respond_uci("Engine", "Author");
+///
+/// assert_eq!(commands.last(), Some(&UciCommand::UciOk));
+/// ```
+pub fn respond_uci(name: &str, author: &str) -> Vec<UciCommand> {
+    vec![
+        UciCommand::Id {
+            name: name.to_string(),
+            author: author.to_string(),
+        },
+        UciCommand::UciOk,
+    ]
+}
+
 pub trait Uci {
     fn send_command(&mut self, command: UciCommand);
     fn read_command(&mut self) -> Option<UciCommand>;
     fn handle_command(&mut self);
+
+    /// Check if the engine should stop running, checked by [`Uci::run`] after every command.
+    fn should_quit(&self) -> bool {
+        false
+    }
+
+    /// Handle commands until [`Uci::should_quit`] returns `true`.
+    fn run(&mut self) {
+        loop {
+            self.handle_command();
+
+            if self.should_quit() {
+                break;
+            }
+        }
+    }
 }
@@ -0,0 +1,141 @@
+use chessframe::{
+    bitboard::{BitBoard, EMPTY},
+    direction::{DIRECTIONS, Direction},
+    file::File,
+    magic::get_file,
+    square::{SQUARES, Square},
+};
+
+#[test]
+fn test_fills() {
+    let pawn = BitBoard::from_square(Square::E2);
+
+    let mut expected = EMPTY;
+    for square in [
+        Square::E2,
+        Square::E3,
+        Square::E4,
+        Square::E5,
+        Square::E6,
+        Square::E7,
+        Square::E8,
+    ] {
+        expected |= BitBoard::from_square(square);
+    }
+
+    assert_eq!(pawn.fill_north(), expected);
+    assert_eq!(pawn.fill_south(), BitBoard::from_square(Square::E1) | pawn);
+    assert_eq!(pawn.fill_file(), get_file(File::E));
+
+    assert_eq!(EMPTY.fill_file(), EMPTY);
+    for square in SQUARES {
+        assert_eq!(
+            BitBoard::from_square(square).fill_file(),
+            get_file(square.file())
+        );
+    }
+}
+
+#[test]
+fn test_hex_formatting() {
+    assert_eq!(format!("{:#x}", BitBoard(0xFF)), "0xff");
+    assert_eq!(format!("{:X}", BitBoard(0xFF)), "FF");
+    assert_eq!(BitBoard(0xFF).to_hex(), "0x00000000000000FF");
+    assert_eq!(EMPTY.to_hex(), "0x0000000000000000");
+}
+
+#[test]
+fn test_checked_shifts() {
+    assert_eq!(BitBoard(1).checked_shl(64), EMPTY);
+    assert_eq!(BitBoard(1).checked_shl(63), BitBoard(1 << 63));
+    assert_eq!(BitBoard(1 << 63).checked_shr(64), EMPTY);
+    assert_eq!(BitBoard(1 << 63).checked_shr(63), BitBoard(1));
+    assert_eq!(BitBoard(1).checked_shl(usize::MAX), EMPTY);
+}
+
+#[test]
+fn test_shift_dir() {
+    for direction in DIRECTIONS {
+        for square in SQUARES {
+            let expected = square.step(direction).map_or(EMPTY, BitBoard::from_square);
+
+            assert_eq!(BitBoard::from_square(square).shift_dir(direction), expected);
+        }
+    }
+
+    assert_eq!(get_file(File::H).shift_dir(Direction::East), EMPTY);
+    assert_eq!(get_file(File::A).shift_dir(Direction::West), EMPTY);
+    assert_eq!(
+        get_file(File::A).shift_dir(Direction::East),
+        get_file(File::B)
+    );
+}
+
+#[test]
+fn test_collect_squares() {
+    let bitboard: BitBoard = [Square::A1, Square::H8].into_iter().collect();
+    assert_eq!(bitboard, BitBoard(0x8000000000000001));
+    assert_eq!(
+        bitboard.collect::<Vec<Square>>(),
+        vec![Square::A1, Square::H8]
+    );
+
+    let squares = [Square::E4, Square::D5, Square::C3, Square::E4];
+    let bitboard: BitBoard = squares.into_iter().collect();
+    assert_eq!(bitboard.count_ones(), 3);
+    assert_eq!(
+        bitboard.collect::<Vec<Square>>(),
+        vec![Square::C3, Square::E4, Square::D5]
+    );
+
+    let mut bitboard = EMPTY;
+    bitboard.extend(SQUARES);
+    assert_eq!(bitboard, !EMPTY);
+    assert_eq!(bitboard.collect::<BitBoard>(), !EMPTY);
+    assert_eq!(std::iter::empty().collect::<BitBoard>(), EMPTY);
+}
+
+#[test]
+fn test_set_relations() {
+    let file = get_file(File::E);
+    let square = BitBoard::from_square(Square::E4);
+    let diagonal = BitBoard(0x8040201008040201);
+
+    assert!(file.contains(Square::E4));
+    assert!(!file.contains(Square::D4));
+
+    assert!(square.is_subset(file));
+    assert!(!file.is_subset(square));
+    assert!(file.is_subset(file));
+    assert!(EMPTY.is_subset(square));
+    assert!(file.is_subset(!EMPTY));
+
+    assert!(file.is_superset(square));
+    assert!(!square.is_superset(file));
+    assert!(file.is_superset(EMPTY));
+    assert!(!EMPTY.is_superset(square));
+
+    assert!(file.intersects(diagonal));
+    assert!(!square.intersects(diagonal));
+    assert!(!file.intersects(EMPTY));
+    assert_eq!(file & diagonal, BitBoard::from_square(Square::E5));
+}
+
+#[test]
+fn test_bit_counts() {
+    assert!(!EMPTY.is_single());
+    assert!(!EMPTY.more_than_one());
+
+    for square in SQUARES {
+        let bitboard = BitBoard::from_square(square);
+        assert!(bitboard.is_single());
+        assert!(!bitboard.more_than_one());
+    }
+
+    let two = BitBoard::from_square(Square::A1) | BitBoard::from_square(Square::H8);
+    assert!(!two.is_single());
+    assert!(two.more_than_one());
+
+    assert!(!(!EMPTY).is_single());
+    assert!((!EMPTY).more_than_one());
+}
@@ -1,11 +1,21 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
 use chessframe::{
     bitboard::{BitBoard, EMPTY},
     board::*,
-    chess_move::ChessMove,
+    castling_rights::CastlingRights,
+    chess_move::{ChessMove, MoveMetaData},
     color::Color,
     error::Error,
-    piece::Piece,
-    square::Square,
+    piece::{PIECES, Piece},
+    square::{SQUARES, Square},
+};
+use rand_chacha::{
+    ChaCha8Rng,
+    rand_core::{Rng, SeedableRng},
 };
 
 #[test]
@@ -86,6 +96,49 @@ fn test_to_fen() {
     let board = Board::from_fen(fen);
 
     assert_eq!(board.to_fen(), fen);
+
+    let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+    let mut board = Board::from_fen(fen);
+    assert_eq!(board.to_fen(), fen);
+
+    let mv = ChessMove::new(Square::F1, Square::B5);
+    let unmake_data = board.unmake_data();
+    let metadata = board.make_move_metadata(mv).unwrap();
+    assert_eq!(
+        board.to_fen(),
+        "r1bqkbnr/pppp1ppp/2n5/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3"
+    );
+
+    let board_after_black = board
+        .make_move_new(ChessMove::new(Square::A7, Square::A6))
+        .unwrap();
+    assert_eq!(
+        board_after_black.to_fen(),
+        "r1bqkbnr/1ppp1ppp/p1n5/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 0 4"
+    );
+
+    board.unmake_move(mv, metadata, unmake_data).unwrap();
+    assert_eq!(board.to_fen(), fen);
+}
+
+#[test]
+fn test_to_fen_without_castling_rights() {
+    let fen = "4k3/8/8/8/8/8/8/R3K2R b - - 0 1";
+    let board = Board::from_fen(fen);
+
+    assert_eq!(board.to_fen(), fen);
+
+    let board = Board::from_fen("r3k3/8/8/8/8/8/8/4K2R w Kq - 0 1")
+        .make_move_new(ChessMove::new(Square::H1, Square::H7))
+        .unwrap();
+
+    assert_eq!(board.to_fen(), "r3k3/7R/8/8/8/8/8/4K3 b q - 1 1");
+
+    let board = board
+        .make_move_new(ChessMove::new(Square::A8, Square::A1))
+        .unwrap();
+
+    assert_eq!(board.to_fen(), "4k3/7R/8/8/8/8/8/r3K3 w - - 2 2");
 }
 
 #[test]
@@ -254,17 +307,57 @@ fn test_hash() {
     let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
     let mut board = Board::from_fen(fen);
 
-    assert_eq!(board.hash, 0x89FB87680071BBF1);
+    assert_eq!(board.hash, 0x1D0D28B8BD0816CA);
     assert_eq!(board.hash(), 0x1D0D28B8BD0816CA);
 
     board
         .make_move(ChessMove::new(Square::E2, Square::E4))
         .unwrap();
 
-    assert_eq!(board.hash, 0xD392461D4BD9B816);
+    assert_eq!(board.hash, 0xE4866C8BF44CB8F2);
     assert_eq!(board.hash(), 0xE4866C8BF44CB8F2);
 }
 
+#[test]
+fn test_repetition_key() {
+    let mut board = Board::from_fen("r3k2r/8/8/8/8/8/8/RN2K2R w KQkq - 0 1");
+    let key = board.repetition_key();
+    assert_eq!(key, board.hash());
+
+    // The knight goes out and comes back, while the black king shuffles.
+    for (from, to) in [
+        (Square::B1, Square::C3),
+        (Square::A8, Square::B8),
+        (Square::C3, Square::B1),
+        (Square::B8, Square::A8),
+    ] {
+        board.make_move(ChessMove::new(from, to)).unwrap();
+        assert_ne!(board.repetition_key(), key);
+    }
+
+    // Black lost the right to castle queenside by moving the rook.
+    assert_ne!(board.repetition_key(), key);
+    assert_eq!(
+        board.repetition_key(),
+        Board::from_fen("r3k2r/8/8/8/8/8/8/RN2K2R w KQk - 4 3").repetition_key()
+    );
+
+    let mut board = Board::from_fen("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1");
+    let key = board.repetition_key();
+
+    for (from, to) in [
+        (Square::B1, Square::C3),
+        (Square::E8, Square::E7),
+        (Square::C3, Square::B1),
+        (Square::E7, Square::E8),
+    ] {
+        board.make_move(ChessMove::new(from, to)).unwrap();
+    }
+
+    assert_eq!(board.repetition_key(), key);
+    assert_eq!(board.half_moves, 4);
+}
+
 #[test]
 fn test_can_castle() {
     let fen = "r1bqk2r/ppp2ppp/2np1n2/2b1p3/2B1P3/2PP1N2/PP3PPP/RNBQK2R w KQkq - 1 6";
@@ -317,3 +410,1085 @@ fn test_can_castle() {
         );
     }
 }
+
+#[test]
+fn test_capture_sequence() {
+    let board = Board::from_fen("3rk3/8/4pn2/3p4/4P3/2N5/3Q4/3RK3 w - - 0 1");
+
+    assert_eq!(
+        board.capture_sequence(Square::D5),
+        vec![
+            (Color::White, Piece::Pawn),
+            (Color::Black, Piece::Pawn),
+            (Color::White, Piece::Knight),
+            (Color::Black, Piece::Knight),
+            (Color::White, Piece::Queen),
+            (Color::Black, Piece::Rook),
+            (Color::White, Piece::Rook),
+        ]
+    );
+}
+
+#[test]
+fn test_capture_sequence_king_only_captures_undefended() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/3pK3/8 w - - 0 1");
+    assert_eq!(
+        board.capture_sequence(Square::D2),
+        vec![(Color::White, Piece::King)]
+    );
+
+    let board = Board::from_fen("4k3/8/8/8/8/1n6/3pK3/8 w - - 0 1");
+    assert_eq!(board.capture_sequence(Square::D2), vec![]);
+}
+
+#[test]
+fn test_check_count() {
+    let board = Board::default();
+    assert_eq!(board.check_count(), 0);
+
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1");
+    assert_eq!(board.check_count(), 1);
+
+    let board = Board::from_fen("4r2k/8/8/8/1b6/8/8/4K3 w - - 0 1");
+    assert_eq!(board.check_count(), 2);
+
+    let moves = board.generate_moves_vec(!EMPTY);
+    assert!(moves.iter().all(|mv| mv.from == Square::E1));
+}
+
+#[test]
+fn test_is_double_check() {
+    let board = Board::from_fen("4k3/8/8/8/4N3/8/8/4R1K1 w - - 0 1");
+    assert!(!board.is_double_check());
+
+    // The knight checks from f6 and discovers a check from the rook on e1.
+    let board = board
+        .make_move_new(ChessMove::new(Square::E4, Square::F6))
+        .unwrap();
+    assert!(board.in_check());
+    assert!(board.is_double_check());
+
+    let board = Board::from_fen("4k3/8/8/8/4N3/8/8/4R1K1 w - - 0 1")
+        .make_move_new(ChessMove::new(Square::E4, Square::C5))
+        .unwrap();
+    assert!(board.in_check());
+    assert!(!board.is_double_check());
+}
+
+#[test]
+fn test_from_fen_in_check() {
+    // Knight check against the side to move.
+    let board = Board::from_fen("4k3/8/8/8/8/5n2/8/4K3 w - - 0 1");
+    assert!(board.in_check());
+
+    // Pawn check against the side to move.
+    let board = Board::from_fen("4k3/8/8/8/8/8/3p4/4K3 w - - 0 1");
+    assert!(board.in_check());
+
+    // Slider check against the side to move.
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1");
+    assert!(board.in_check());
+
+    let board = Board::from_fen("4k3/8/3N4/8/8/8/8/4K3 b - - 0 1");
+    assert!(board.in_check());
+
+    // Attacks on the king of the side not to move don't count.
+    let board = Board::from_fen("4k3/8/3N4/8/8/8/8/4K3 w - - 0 1");
+    assert!(!board.in_check());
+
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K2r b - - 0 1");
+    assert!(!board.in_check());
+}
+
+#[test]
+fn test_hash_after() {
+    let fens = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/Pp2P3/2N2Q1p/1PPBBPPP/R3K2R b KQkq a3 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "r1bqk2r/pppp1pb1/2n2np1/4p1Pp/2B1P3/3P1N2/PPP2P1P/RNBQK2R w KQkq h6 0 7",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    ];
+
+    for fen in fens {
+        let board = Board::from_fen(fen);
+
+        for mv in board.generate_moves_vec(!EMPTY) {
+            if let Ok(new_board) = board.make_move_new(mv) {
+                assert_eq!(board.hash_after(mv), new_board.hash(), "{} {}", fen, mv);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_see_ge() {
+    // Pawn takes pawn, pawn takes back: the exchange is even.
+    let board = Board::from_fen("4k3/8/4p3/3p4/4P3/8/8/4K3 w - - 0 1");
+    let mv = ChessMove::new(Square::E4, Square::D5);
+    assert!(board.see_ge(mv, 0));
+    assert!(!board.see_ge(mv, 1));
+    assert!(board.see_ge(mv, -100));
+
+    // Undefended pawn.
+    let board = Board::from_fen("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1");
+    let mv = ChessMove::new(Square::E4, Square::D5);
+    assert!(board.see_ge(mv, 100));
+    assert!(!board.see_ge(mv, 101));
+
+    // Queen takes a defended pawn.
+    let board = Board::from_fen("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1");
+    let mv = ChessMove::new(Square::D1, Square::D5);
+    assert!(!board.see_ge(mv, 0));
+    assert!(board.see_ge(mv, -800));
+
+    // Even with the rook behind the queen, the queen is traded for two pawns.
+    let board = Board::from_fen("4k3/8/4p3/3p4/8/8/3Q4/3RK3 w - - 0 1");
+    let mv = ChessMove::new(Square::D2, Square::D5);
+    assert!(!board.see_ge(mv, 0));
+
+    // The king can only recapture if the rook isn't defended.
+    let board = Board::from_fen("4k3/3p4/8/8/8/8/3R4/3RK3 w - - 0 1");
+    let mv = ChessMove::new(Square::D2, Square::D7);
+    assert!(board.see_ge(mv, 100));
+
+    let board = Board::from_fen("4k3/3p4/8/8/8/8/3R4/4K3 w - - 0 1");
+    let mv = ChessMove::new(Square::D2, Square::D7);
+    assert!(!board.see_ge(mv, 0));
+    assert!(board.see_ge(mv, -400));
+
+    // Capturing en passant removes the pawn on d5, so the rook on d1 defends d6 and the rook on
+    // d8 can't recapture.
+    let board = Board::from_fen("3rk3/8/8/3pP3/8/8/8/3RK3 w - d6 0 1");
+    let mv = ChessMove::new(Square::E5, Square::D6);
+    assert!(board.see_ge(mv, 100));
+    assert!(!board.see_ge(mv, 101));
+
+    // Without the rook on d1 the pawn is lost after the rook recaptures.
+    let board = Board::from_fen("3rk3/8/8/3pP3/8/8/8/4K3 w - d6 0 1");
+    let mv = ChessMove::new(Square::E5, Square::D6);
+    assert!(board.see_ge(mv, 0));
+    assert!(!board.see_ge(mv, 1));
+}
+
+#[test]
+fn test_non_pawn_material() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+    assert_eq!(board.non_pawn_material(Color::White), 0);
+    assert_eq!(board.non_pawn_material(Color::Black), 0);
+
+    let board = Board::from_fen("4k3/8/8/8/8/8/4P3/3NK2R b - - 0 1");
+    assert_eq!(board.non_pawn_material(Color::White), 800);
+    assert_eq!(board.non_pawn_material(Color::Black), 0);
+}
+
+#[test]
+fn test_is_endgame() {
+    assert!(!Board::default().is_endgame());
+
+    let board = Board::from_fen("4k3/5pp1/8/8/8/8/r4PP1/3R2K1 w - - 0 1");
+    assert!(board.is_endgame());
+
+    // A queen each, but little else left.
+    let board = Board::from_fen("3qk3/5pp1/8/8/8/8/5PP1/3QK3 w - - 0 1");
+    assert!(board.is_endgame());
+
+    let board = Board::from_fen("r2qk2r/5pp1/8/8/8/8/5PP1/R2QK2R w - - 0 1");
+    assert!(!board.is_endgame());
+}
+
+#[test]
+fn test_from_array() {
+    let board = Board::default();
+    let squares = board.to_array();
+
+    assert_eq!(squares.iter().flatten().count(), 32);
+    assert_eq!(
+        squares[Square::D8.to_index()],
+        Some((Piece::Queen, Color::Black))
+    );
+
+    let from_array = Board::from_array(
+        squares,
+        board.side_to_move,
+        board.castling_rights,
+        board.en_passant_square(),
+    )
+    .unwrap();
+    assert_eq!(from_array, board);
+    assert_eq!(from_array.hash(), board.hash());
+    assert_eq!(from_array.to_array(), squares);
+
+    let board = Board::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3");
+    let from_array = Board::from_array(
+        board.to_array(),
+        Color::White,
+        board.castling_rights,
+        Some(Square::F6),
+    )
+    .unwrap();
+    assert_eq!(from_array, board);
+    assert_eq!(from_array.hash(), board.hash());
+    assert!(from_array.verify_hash());
+
+    assert_eq!(
+        Board::from_array([None; 64], Color::White, board.castling_rights, None),
+        Err(Error::InvalidBoard)
+    );
+}
+
+#[test]
+fn test_iter_pieces() {
+    let board = Board::default();
+    let pieces: Vec<(Square, Piece, Color)> = board.iter_pieces().collect();
+
+    assert_eq!(pieces.len(), 32);
+
+    let kings: Vec<(Square, Color)> = pieces
+        .iter()
+        .filter(|(_, piece, _)| *piece == Piece::King)
+        .map(|(square, _, color)| (*square, *color))
+        .collect();
+    assert_eq!(
+        kings,
+        vec![(Square::E1, Color::White), (Square::E8, Color::Black)]
+    );
+
+    for (square, piece, color) in pieces {
+        assert_eq!(board.get_piece(square), Some(piece));
+        assert!(board.occupancy(color).is_set(square));
+    }
+}
+
+#[test]
+fn test_material_signature() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    assert_eq!(board.material_signature(), "KRvK");
+
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/1N2KB2 w - - 0 1");
+    assert_eq!(board.material_signature(), "KBNvK");
+
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/4K2r w - - 0 1");
+    assert_eq!(board.material_signature(), "KvKR");
+
+    // The key only depends on the piece counts, and tells the colors apart.
+    let rook = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    let moved_rook = Board::from_fen("4k3/8/8/3R4/8/8/8/4K3 b - - 0 1");
+    assert_eq!(rook.material_key(), moved_rook.material_key());
+    assert_ne!(rook.material_key(), board.material_key());
+}
+
+#[test]
+fn test_set_side_to_move() {
+    let mut board = Board::from_fen("4k3/8/8/8/4R3/8/8/4K3 w - - 0 1");
+    assert!(!board.in_check());
+
+    board.set_side_to_move(Color::Black);
+    assert_eq!(board.side_to_move, Color::Black);
+    assert!(board.in_check());
+    let expected = Board::from_fen("4k3/8/8/8/4R3/8/8/4K3 b - - 0 1");
+    assert_eq!(board, expected);
+    assert_eq!(board.pinned(), expected.pinned());
+
+    board.set_side_to_move(Color::White);
+    assert!(!board.in_check());
+
+    // The en passant square only belongs to the side to move.
+    let mut board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1");
+    assert_eq!(board.en_passant_square(), Some(Square::D6));
+
+    board.set_side_to_move(Color::White);
+    assert_eq!(board.en_passant_square(), Some(Square::D6));
+
+    board.set_side_to_move(Color::Black);
+    assert_eq!(board.en_passant_square(), None);
+}
+
+#[test]
+fn test_fen_fields() {
+    let board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K2R w K d6 7 40");
+    let mut fields = board.fen_fields();
+
+    assert_eq!(fields.placement, "4k3/8/8/3pP3/8/8/8/4K2R");
+    assert_eq!(fields.side_to_move, Color::White);
+    assert_eq!(fields.en_passant_square, Some(Square::D6));
+    assert_eq!(fields.half_moves, 7);
+    assert_eq!(fields.full_moves, 40);
+    assert_eq!(fields.to_fen(), "4k3/8/8/3pP3/8/8/8/4K2R w K d6 7 40");
+    assert_eq!(fields.to_fen(), board.to_fen());
+
+    fields.side_to_move = Color::Black;
+    fields.en_passant_square = None;
+    assert_eq!(fields.to_fen(), "4k3/8/8/3pP3/8/8/8/4K2R b K - 7 40");
+
+    let rebuilt = Board::from_fen(&fields.to_fen());
+    assert_eq!(rebuilt.side_to_move, Color::Black);
+    assert_eq!(rebuilt.fen_fields(), fields);
+}
+
+#[test]
+fn test_why_illegal() {
+    let board = Board::default();
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::G1, Square::F3)),
+        None
+    );
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::D4, Square::D5)),
+        Some(IllegalReason::NoPiece)
+    );
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::G8, Square::F6)),
+        Some(IllegalReason::WrongColor)
+    );
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::F1, Square::C4)),
+        Some(IllegalReason::NotPseudoLegal)
+    );
+
+    // The bishop on d2 is pinned by the bishop on b4.
+    let board = Board::from_fen("4k3/8/8/8/1b6/8/3B4/4K3 w - - 0 1");
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::D2, Square::E3)),
+        Some(IllegalReason::LeavesKingInCheck)
+    );
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::D2, Square::C3)),
+        None
+    );
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::E1, Square::D1)),
+        None
+    );
+
+    // The king can't step into the rook's file.
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1");
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::E1, Square::D1)),
+        Some(IllegalReason::LeavesKingInCheck)
+    );
+
+    // The rook on f8 covers f1, so white can't castle kingside, but queenside is fine.
+    let board = Board::from_fen("4kr2/8/8/8/8/8/8/R3K2R w KQ - 0 1");
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::E1, Square::G1)),
+        Some(IllegalReason::CastleThroughCheck)
+    );
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::E1, Square::C1)),
+        None
+    );
+
+    // Without the castling rights it isn't a castle at all.
+    let board = Board::from_fen("4kr2/8/8/8/8/8/8/R3K2R w - - 0 1");
+    assert_eq!(
+        board.why_illegal(ChessMove::new(Square::E1, Square::G1)),
+        Some(IllegalReason::NotPseudoLegal)
+    );
+}
+
+#[test]
+fn test_is_tactical() {
+    let board = Board::default();
+    assert!(!board.is_tactical(ChessMove::new(Square::G1, Square::F3)));
+    assert!(!board.is_tactical(ChessMove::new(Square::E2, Square::E4)));
+
+    let board = Board::from_fen("4k3/8/8/8/4q3/8/8/4RK2 w - - 0 1");
+    assert!(board.is_tactical(ChessMove::new(Square::E1, Square::E4)));
+
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    assert!(board.is_tactical(ChessMove::new(Square::A1, Square::A8)));
+    assert!(!board.is_tactical(ChessMove::new(Square::A1, Square::A7)));
+}
+
+#[test]
+fn test_try_from_fen() {
+    let board = Board::try_from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -");
+    assert_eq!(board, Ok(Board::default()));
+
+    let board = Board::try_from_fen("  4k3/8/8/8/8/8/8/4K3   b  -  -  12   40 ").unwrap();
+    assert_eq!(board.side_to_move, Color::Black);
+    assert_eq!(board.half_moves, 12);
+
+    for fen in [
+        "",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNRR w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQXBNR w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQQBNR w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e9 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - x 1",
+    ] {
+        assert_eq!(Board::try_from_fen(fen), Err(Error::InvalidFen), "{}", fen);
+    }
+}
+
+#[test]
+fn test_eq_fen() {
+    let mut board = Board::default();
+    assert_eq!(
+        board,
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    );
+
+    board
+        .make_move(ChessMove::new(Square::E2, Square::E4))
+        .unwrap();
+    assert_eq!(
+        board,
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+    );
+    assert_ne!(
+        board,
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 1"
+    );
+
+    // The move counters aren't part of the position.
+    assert!(board == *"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 3 2");
+
+    // An invalid FEN is never equal, and doesn't panic.
+    assert_ne!(board, "garbage");
+    assert_ne!(board, "");
+    assert!(board != *"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR x KQkq - 0 1");
+}
+
+#[test]
+fn test_transposition_eq_and_hash() {
+    fn hash_of(board: &Board) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        Hash::hash(board, &mut hasher);
+        hasher.finish()
+    }
+
+    let play = |moves: [(Square, Square); 3]| {
+        let mut board = Board::default();
+        for (from, to) in moves {
+            board.make_move(ChessMove::new(from, to)).unwrap();
+        }
+        board
+    };
+
+    let first = play([
+        (Square::G1, Square::F3),
+        (Square::G8, Square::F6),
+        (Square::B1, Square::C3),
+    ]);
+    let second = play([
+        (Square::B1, Square::C3),
+        (Square::G8, Square::F6),
+        (Square::G1, Square::F3),
+    ]);
+
+    assert_eq!(first, second);
+    assert_eq!(hash_of(&first), hash_of(&second));
+
+    // The cached fields and the move counters don't matter.
+    let mut cached = first;
+    cached.half_moves = 10;
+    cached.update_pins();
+    assert_eq!(cached, first);
+    assert_eq!(hash_of(&cached), hash_of(&first));
+
+    assert_ne!(first, Board::default());
+}
+
+#[test]
+fn test_board_as_hash_map_key() {
+    let mut positions = HashMap::new();
+
+    let mut board = Board::default();
+    positions.insert(board, 0);
+
+    board
+        .make_move(ChessMove::new(Square::G1, Square::F3))
+        .unwrap();
+    positions.insert(board, 1);
+
+    board
+        .make_move(ChessMove::new(Square::G8, Square::F6))
+        .unwrap();
+    board
+        .make_move(ChessMove::new(Square::F3, Square::G1))
+        .unwrap();
+    board
+        .make_move(ChessMove::new(Square::F6, Square::G8))
+        .unwrap();
+
+    // Back to the starting position, only the move counters differ.
+    *positions.entry(board).or_insert(0) += 1;
+
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions[&Board::default()], 1);
+}
+
+#[test]
+fn test_mobility() {
+    let board = Board::default();
+    assert_eq!(
+        board.mobility(Color::White, false),
+        board.mobility(Color::Black, false)
+    );
+    assert_eq!(board.mobility(Color::White, false), 4);
+    assert_eq!(board.mobility(Color::White, true), 16 + 4);
+    assert_eq!(board.mobility(Color::Black, true), 16 + 4);
+
+    // The rook is blocked by its own king on the first rank.
+    let board = Board::from_fen("7k/8/8/8/8/8/8/K2R4 w - - 0 1");
+    assert_eq!(board.mobility(Color::White, false), 13 + 3);
+    assert_eq!(board.mobility(Color::Black, false), 3);
+
+    // Capturing an enemy piece counts, moving onto an own piece doesn't.
+    let board = Board::from_fen("7k/8/8/8/8/8/1p6/NK6 w - - 0 1");
+    assert_eq!(board.mobility(Color::White, false), 2 + 4);
+    assert_eq!(board.mobility(Color::Black, true), 1 + 3);
+
+    // The e3 pawn is blocked, the b2 and d2 pawns can push one or two squares or capture on c3.
+    let board = Board::from_fen("4k3/8/8/8/4p3/2p1P3/1P1P4/4K3 w - - 0 1");
+    assert_eq!(board.mobility(Color::White, true), 3 + 3 + 4);
+    assert_eq!(board.mobility(Color::White, false), 4);
+}
+
+#[test]
+fn test_outposts() {
+    // The d6 pawn is backward, so the knight on d5 can never be chased away by a pawn.
+    let board = Board::from_fen("r1bqkb1r/pp3ppp/3p1n2/4p3/4P3/2N5/PPP2PPP/R1BQKB1R w KQkq - 0 1");
+    assert_eq!(
+        board.outposts(Color::White),
+        BitBoard::from_square(Square::D5)
+    );
+    assert_eq!(board.outposts(Color::Black), EMPTY);
+
+    // With a pawn on c7, black can still play c6.
+    let board = Board::from_fen("r1bqkb1r/ppp2ppp/3p1n2/4p3/4P3/2N5/PPP2PPP/R1BQKB1R w KQkq - 0 1");
+    assert_eq!(board.outposts(Color::White), EMPTY);
+
+    // The same holds for black, mirrored.
+    let board = Board::from_fen("r1bqkb1r/ppp2ppp/2n5/4p3/4P3/3P1N2/PP3PPP/R1BQKB1R b KQkq - 0 1");
+    assert_eq!(
+        board.outposts(Color::Black),
+        BitBoard::from_square(Square::D4)
+    );
+}
+
+#[test]
+fn test_attacks_from() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/1p4P1/R3K3 w - - 0 1");
+
+    // The rook on the open a-file attacks every square up the file and along the first rank up to
+    // its own king.
+    let file = BitBoard(0x0101010101010100);
+    let rank = BitBoard::from_square(Square::B1)
+        | BitBoard::from_square(Square::C1)
+        | BitBoard::from_square(Square::D1)
+        | BitBoard::from_square(Square::E1);
+    assert_eq!(board.attacks_from(Square::A1), file | rank);
+
+    assert_eq!(
+        board.attacks_from(Square::G2),
+        BitBoard::from_square(Square::F3) | BitBoard::from_square(Square::H3)
+    );
+    assert_eq!(
+        board.attacks_from(Square::B2),
+        BitBoard::from_square(Square::A1) | BitBoard::from_square(Square::C1)
+    );
+    assert_eq!(board.attacks_from(Square::D4), EMPTY);
+}
+
+#[test]
+fn test_threatened_squares() {
+    // The knight on c7 forks the king and the rook.
+    let board = Board::from_fen("r3k3/2N5/8/8/8/8/8/4K3 b - - 0 1");
+    let threatened = board.threatened_squares();
+
+    assert!(threatened.is_set(Square::A8));
+    assert!(threatened.is_set(Square::E8));
+    assert!(!threatened.is_set(Square::D8));
+    assert_eq!(threatened, board.attacks_by(Color::White));
+
+    for square in SQUARES {
+        let attackers =
+            board.attackers_to(square, board.combined()) & board.occupancy(Color::White);
+        assert_eq!(threatened.is_set(square), attackers != EMPTY, "{square}");
+    }
+
+    let mut board = board;
+    board.set_side_to_move(Color::White);
+    assert_eq!(board.threatened_squares(), board.attacks_by(Color::Black));
+    assert!(board.threatened_squares().is_set(Square::A1));
+}
+
+#[test]
+fn test_multiple_attackers() {
+    // Both rooks attack d6, the square in front of the black pawn on d7.
+    let board = Board::from_fen("4k3/3p4/8/8/8/8/8/3RK2R w - - 0 1")
+        .make_move_new(ChessMove::new(Square::H1, Square::H6))
+        .unwrap();
+    let attacked = board.multiple_attackers(Color::White);
+
+    assert!(attacked.is_set(Square::D6));
+    assert!(!attacked.is_set(Square::D7));
+    assert!(!attacked.is_set(Square::D4));
+    assert!(!attacked.is_set(Square::H4));
+    assert!(attacked.is_set(Square::D2));
+
+    for square in SQUARES {
+        let attackers =
+            board.attackers_to(square, board.combined()) & board.occupancy(Color::White);
+        assert_eq!(
+            attacked.is_set(square),
+            attackers.count_ones() >= 2,
+            "{square}"
+        );
+    }
+
+    assert_eq!(board.multiple_attackers(Color::Black), EMPTY);
+}
+
+#[test]
+fn test_defenders_of() {
+    let board = Board::from_fen("3rk3/8/8/3N4/4P3/8/8/4K3 w - - 0 1");
+
+    assert_eq!(
+        board.defenders_of(Square::D5),
+        BitBoard::from_square(Square::E4)
+    );
+    assert_eq!(board.defenders_of(Square::E4), EMPTY);
+    assert_eq!(
+        board.defenders_of(Square::D8),
+        BitBoard::from_square(Square::E8)
+    );
+    assert_eq!(board.defenders_of(Square::D4), EMPTY);
+}
+
+#[test]
+fn test_hanging_pieces() {
+    // The bishop on c4 is attacked by the knight and has no defenders.
+    let board = Board::from_fen("4k3/8/8/8/2B5/4n3/8/4K3 w - - 0 1");
+    assert_eq!(
+        board.hanging_pieces(Color::White),
+        BitBoard::from_square(Square::C4)
+    );
+    assert_eq!(board.hanging_pieces(Color::Black), EMPTY);
+
+    // Defended by a pawn, but still attacked by a less valuable pawn.
+    let board = Board::from_fen("4k3/8/8/3p4/2B5/1P6/8/4K3 w - - 0 1");
+    assert_eq!(
+        board.hanging_pieces(Color::White),
+        BitBoard::from_square(Square::C4)
+    );
+
+    // Defended by a pawn and attacked by a more valuable queen.
+    let board = Board::from_fen("4k3/8/8/8/2B3q1/1P6/8/4K3 w - - 0 1");
+    assert_eq!(board.hanging_pieces(Color::White), EMPTY);
+    assert_eq!(board.hanging_pieces(Color::Black), EMPTY);
+
+    assert_eq!(Board::default().hanging_pieces(Color::White), EMPTY);
+}
+
+#[test]
+fn test_material_balance() {
+    fn count_material(board: &Board) -> i32 {
+        PIECES
+            .iter()
+            .map(|piece| {
+                let white = board.pieces_color(*piece, Color::White).count_ones() as i32;
+                let black = board.pieces_color(*piece, Color::Black).count_ones() as i32;
+
+                (white - black) * piece.value()
+            })
+            .sum()
+    }
+
+    let mut board = Board::from_fen("r3k3/1P6/8/3pP3/8/8/8/4K3 w - d6 0 1");
+    assert_eq!(board.material_balance(), count_material(&board));
+
+    for mv in [
+        ChessMove::new(Square::E5, Square::D6),
+        ChessMove::new(Square::E8, Square::D7),
+        ChessMove::new_promotion(Square::B7, Square::A8, Piece::Queen),
+        ChessMove::new(Square::D7, Square::D6),
+    ] {
+        board.make_move(mv).unwrap();
+        assert_eq!(board.material_balance(), count_material(&board));
+    }
+
+    assert_eq!(board.material_balance(), 900);
+    assert_eq!(board.material, [900, 0]);
+    assert_eq!(Board::default().material_balance(), 0);
+    assert_eq!(Board::new().material, [0, 0]);
+}
+
+#[test]
+fn test_to_san() {
+    let board = Board::kiwipete();
+    assert_eq!(board.to_san(ChessMove::new(Square::E1, Square::G1)), "O-O");
+    assert_eq!(
+        board.to_san(ChessMove::new(Square::E1, Square::C1)),
+        "O-O-O"
+    );
+    assert_eq!(board.to_san(ChessMove::new(Square::E5, Square::F7)), "Nxf7");
+    assert_eq!(board.to_san(ChessMove::new(Square::D5, Square::E6)), "dxe6");
+    assert_eq!(
+        board.to_san(ChessMove::new(Square::F3, Square::F7)),
+        "Qxf7+"
+    );
+    assert_eq!(board.to_san(ChessMove::new(Square::C3, Square::B5)), "Nb5");
+
+    // Knights on b1 and f3 can both go to d2, rooks on a1 and a5 to a3.
+    let board = Board::from_fen("4k3/8/8/R7/8/8/8/RN2KN2 w - - 0 1");
+    assert_eq!(board.to_san(ChessMove::new(Square::B1, Square::D2)), "Nbd2");
+    assert_eq!(board.to_san(ChessMove::new(Square::F1, Square::D2)), "Nfd2");
+    assert_eq!(board.to_san(ChessMove::new(Square::A1, Square::A3)), "R1a3");
+    assert_eq!(board.to_san(ChessMove::new(Square::A5, Square::A3)), "R5a3");
+    assert_eq!(board.to_san(ChessMove::new(Square::B1, Square::C3)), "Nc3");
+
+    let board = Board::from_fen("1n2k3/P7/8/3pP3/8/8/8/4K3 w - d6 0 1");
+    assert_eq!(board.to_san(ChessMove::new(Square::E5, Square::D6)), "exd6");
+
+    let promotion = ChessMove::new_promotion(Square::A7, Square::A8, Piece::Queen);
+    assert_eq!(board.to_san(promotion), "a8=Q");
+
+    let promotion = ChessMove::new_promotion(Square::A7, Square::B8, Piece::Knight);
+    assert_eq!(board.to_san(promotion), "axb8=N");
+
+    let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1");
+    assert_eq!(board.to_san(ChessMove::new(Square::A1, Square::A8)), "Ra8#");
+    assert_eq!(board.to_san(ChessMove::new(Square::A1, Square::A7)), "Ra7");
+}
+
+#[test]
+fn test_make_move_logged() {
+    let mut board = Board::from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2");
+
+    assert_eq!(
+        board.make_move_logged(ChessMove::new(Square::E4, Square::D5)),
+        Ok(("exd5".to_string(), MoveMetaData::Capture(Piece::Pawn)))
+    );
+    assert_eq!(
+        board,
+        Board::from_fen("rnbqkbnr/ppp1pppp/8/3P4/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2")
+    );
+
+    let mut board = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1");
+    let before = board;
+
+    assert!(
+        board
+            .make_move_logged(ChessMove::new(Square::E2, Square::C3))
+            .is_err()
+    );
+    assert_eq!(board, before);
+    assert_eq!(board.pinned(), before.pinned());
+    assert_eq!(board.in_check(), before.in_check());
+}
+
+#[test]
+fn test_pin_mask() {
+    let board = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1");
+
+    assert_eq!(
+        board.attacks_from(Square::E2) & board.pin_mask(Square::E2),
+        EMPTY
+    );
+    assert!(
+        board
+            .legal_moves()
+            .iter()
+            .all(|mv| mv.get_move().0 != Square::E2)
+    );
+
+    let board = Board::from_fen("4k3/8/8/b7/8/8/3B4/4K3 w - - 0 1");
+    let ray = BitBoard::from_square(Square::A5)
+        | BitBoard::from_square(Square::B4)
+        | BitBoard::from_square(Square::C3);
+
+    assert_eq!(
+        board.pin_mask(Square::D2),
+        ray | BitBoard::from_square(Square::D2)
+    );
+    assert_eq!(
+        board.attacks_from(Square::D2) & board.pin_mask(Square::D2),
+        ray
+    );
+    assert_eq!(board.pin_mask(Square::E1), !EMPTY);
+    assert_eq!(board.pin_mask(Square::A5), !EMPTY);
+}
+
+#[test]
+fn test_with_move() {
+    let board = Board::default();
+
+    assert_eq!(
+        board.with_move(ChessMove::new(Square::E2, Square::E4)),
+        board
+            .make_move_new(ChessMove::new(Square::E2, Square::E4))
+            .ok()
+    );
+
+    let board = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1");
+
+    assert_eq!(
+        board.with_move(ChessMove::new(Square::E2, Square::C3)),
+        None
+    );
+    assert_eq!(
+        board
+            .generate_moves_vec(!EMPTY)
+            .iter()
+            .filter_map(|mv| board.with_move(*mv))
+            .count(),
+        board.legal_moves().len()
+    );
+}
+
+#[test]
+fn test_san_move() {
+    let board = Board::kiwipete();
+
+    for mv in board.legal_moves() {
+        assert_eq!(format!("{}", board.san_move(mv)), board.to_san(mv));
+    }
+
+    let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1");
+    let mv = ChessMove::new(Square::A1, Square::A8);
+    assert_eq!(format!("{}", board.san_move(mv)), "Ra8#");
+    assert_eq!(board.san_move(mv).to_string(), board.to_san(mv));
+}
+
+#[test]
+fn test_parse_san() {
+    let board = Board::kiwipete();
+
+    for mv in board.legal_moves() {
+        assert_eq!(board.parse_san(&board.to_san(mv)), Ok(mv), "{}", mv);
+    }
+
+    assert_eq!(
+        board.parse_san("0-0"),
+        Ok(ChessMove::new(Square::E1, Square::G1))
+    );
+    assert_eq!(
+        board.parse_san("Qxf6"),
+        Ok(ChessMove::new(Square::F3, Square::F6))
+    );
+    assert_eq!(board.parse_san("Nc3"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_san("e9"), Err(Error::InvalidMove));
+    // Castling is only written as such.
+    assert_eq!(board.parse_san("Kg1"), Err(Error::InvalidMove));
+
+    // More disambiguation than needed is accepted, too little isn't.
+    let board = Board::from_fen("4k3/P7/8/8/8/8/8/1N2KN2 w - - 0 1");
+    let b1c3 = ChessMove::new(Square::B1, Square::C3);
+    let b1d2 = ChessMove::new(Square::B1, Square::D2);
+    assert_eq!(board.parse_san("Nc3"), Ok(b1c3));
+    assert_eq!(board.parse_san("Nbc3"), Ok(b1c3));
+    assert_eq!(board.parse_san("N1c3"), Ok(b1c3));
+    assert_eq!(board.parse_san("Nb1c3"), Ok(b1c3));
+    assert_eq!(board.parse_san("Nbd2"), Ok(b1d2));
+    assert_eq!(board.parse_san("Nb1d2"), Ok(b1d2));
+    assert_eq!(board.parse_san("Nd2"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_san("Nfc3"), Err(Error::InvalidMove));
+
+    let promotion = ChessMove::new_promotion(Square::A7, Square::A8, Piece::Queen);
+    assert_eq!(board.parse_san("a8=Q+"), Ok(promotion));
+    assert_eq!(board.parse_san("a8Q+"), Ok(promotion));
+    assert_eq!(board.parse_san("a7a8Q"), Ok(promotion));
+    assert_eq!(board.parse_san("a8"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_san("a8=K"), Err(Error::InvalidMove));
+}
+
+#[test]
+fn test_parse_move() {
+    let mut board = Board::default();
+    let mv = ChessMove::new(Square::G1, Square::F3);
+
+    assert_eq!(board.parse_move("Nf3"), Ok(mv));
+    assert_eq!(board.parse_move("g1f3"), Ok(mv));
+    assert_eq!(board.parse_move(" g1f3 "), Ok(mv));
+    assert_eq!(board.parse_move("Nf3"), board.parse_move("g1f3"));
+
+    assert_eq!(board.parse_move("Nf4"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_move("g1f4"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_move(""), Err(Error::InvalidMove));
+
+    // Only lowercase promotion pieces are UCI, anything else is left to SAN.
+    assert_eq!(board.parse_move("a7a8Q"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_move("e1e2x"), Err(Error::InvalidMove));
+    assert_eq!(board.parse_move("e2e4K"), Err(Error::InvalidMove));
+
+    // The bishop on e2 is pinned, so the UCI move isn't legal.
+    let mut board = Board::from_fen("4k3/4r3/8/8/8/8/4B3/4K3 w - - 0 1");
+    assert_eq!(board.parse_move("e2d3"), Err(Error::InvalidMove));
+
+    let mut board = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1");
+    let promotion = ChessMove::new_promotion(Square::B7, Square::B8, Piece::Knight);
+    assert_eq!(board.parse_move("b7b8n"), Ok(promotion));
+    assert_eq!(board.parse_move("b8=N+"), Ok(promotion));
+}
+
+#[test]
+fn test_play_san_line() {
+    let mut board = Board::default();
+
+    assert_eq!(board.play_san_line("e4 e5 Nf3 Nc6 Bb5"), Ok(()));
+    assert_eq!(
+        board.to_fen(),
+        "r1bqkbnr/pppp1ppp/2n5/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3"
+    );
+
+    let before = board;
+    assert_eq!(
+        board.play_san_line("a6 Ba4 Nf6 Kd2"),
+        Err(Error::InvalidMove)
+    );
+    assert_eq!(board, before);
+}
+
+#[test]
+fn test_block_squares() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1");
+
+    assert_eq!(
+        board.block_squares(),
+        BitBoard::from_square(Square::B1)
+            | BitBoard::from_square(Square::C1)
+            | BitBoard::from_square(Square::D1)
+    );
+
+    // Adjacent and knight checks can't be blocked.
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/3rK3 w - - 0 1");
+    assert_eq!(board.block_squares(), EMPTY);
+    let board = Board::from_fen("4k3/8/8/8/8/3n4/8/4K3 w - - 0 1");
+    assert_eq!(board.block_squares(), EMPTY);
+
+    // Neither can double checks.
+    let board = Board::from_fen("4r2k/8/8/8/1b6/8/8/4K3 w - - 0 1");
+    assert_eq!(board.block_squares(), EMPTY);
+}
+
+#[test]
+fn test_verify_hash() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0xC0FFEE);
+
+    for _ in 0..20 {
+        let mut board = Board::default();
+
+        for _ in 0..50 {
+            let moves = board.legal_moves();
+            if moves.is_empty() {
+                break;
+            }
+
+            let mv = moves[rng.next_u32() as usize % moves.len()];
+            board.make_move(mv).unwrap();
+
+            assert!(board.verify_hash(), "{}", board.to_fen());
+        }
+    }
+
+    let mut board = Board::kiwipete();
+    assert!(board.verify_hash());
+
+    board.hash ^= 1;
+    assert!(!board.verify_hash());
+}
+
+#[test]
+fn test_hash_fuzz() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0xDECAF);
+
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "r3k2r/8/8/2pP4/8/8/1p4P1/R3K2R w KQkq c6 0 1",
+    ] {
+        for _ in 0..10 {
+            let mut board = Board::from_fen(fen);
+
+            for _ in 0..200 {
+                let moves = board.legal_moves();
+                if moves.is_empty() {
+                    break;
+                }
+
+                let mv = moves[rng.next_u32() as usize % moves.len()];
+
+                let mut fast = board;
+                fast.make_move_fast(mv).unwrap();
+
+                let mut unmade = board;
+                let unmake_data = unmade.unmake_data();
+                let metadata = unmade.make_move_metadata(mv).unwrap();
+                unmade.unmake_move(mv, metadata, unmake_data).unwrap();
+                assert_eq!(unmade.hash(), board.hash(), "{} {}", board.to_fen(), mv);
+
+                let hash_after = board.hash_after(mv);
+                board.make_move(mv).unwrap();
+
+                assert!(board.verify_hash(), "{} {}", board.to_fen(), mv);
+                assert_eq!(board.hash(), hash_after, "{} {}", board.to_fen(), mv);
+                assert_eq!(board.hash(), fast.hash(), "{} {}", board.to_fen(), mv);
+
+                if rng.next_u32() % 8 == 0
+                    && let Ok(null) = board.make_null_move_new()
+                {
+                    assert!(null.verify_hash(), "{}", null.to_fen());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_incremental_hash() {
+    for fen in [
+        "r3k2r/8/8/2pP4/8/8/8/R3K2R w KQkq c6 0 1",
+        "r3k2r/8/8/8/3pP3/8/8/R3K2R b Kq e3 0 1",
+        "4k3/8/8/8/8/8/8/4K3 b - - 0 1",
+    ] {
+        let mut board = Board::from_fen(fen);
+        assert_eq!(board.hash, board.hash());
+        assert!(board.verify_hash(), "{fen}");
+
+        board.remove_castling_rights(CastlingRights::from_fen("Kk"));
+        assert!(board.verify_hash(), "{fen}");
+
+        board.add_castling_rights(CastlingRights::from_fen("K"));
+        assert!(board.verify_hash(), "{fen}");
+
+        board.set_side_to_move(!board.side_to_move);
+        assert_eq!(board.en_passant_square(), None);
+        assert!(board.verify_hash(), "{fen}");
+    }
+
+    let mut board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+    let mv = ChessMove::new(Square::E1, Square::G1);
+    let unmake_data = board.unmake_data();
+    let metadata = board.make_move_metadata(mv).unwrap();
+
+    assert_eq!(board.castling_rights, CastlingRights::from_fen("kq"));
+    assert_eq!(
+        board.hash(),
+        Board::from_fen("r3k2r/8/8/8/8/8/8/R4RK1 b kq - 1 1").hash()
+    );
+
+    board.unmake_move(mv, metadata, unmake_data).unwrap();
+    assert_eq!(
+        board.hash(),
+        Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").hash()
+    );
+
+    let board = Board::from_fen("4k3/8/8/8/3p4/8/4P3/4K3 w - - 0 1")
+        .make_move_new(ChessMove::new(Square::E2, Square::E4))
+        .unwrap();
+    assert_eq!(
+        board.hash(),
+        Board::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1").hash()
+    );
+    assert!(board.verify_hash());
+}
@@ -0,0 +1,87 @@
+use chessframe::{castling_rights::CastlingRights, color::Color, square::Square};
+
+#[test]
+fn test_signatures() {
+    // `Color` is `Copy`, so every method takes it by value.
+    let _: fn(&CastlingRights, Color, bool) -> bool = CastlingRights::can_castle;
+    let _: fn(&mut CastlingRights, Color, bool) -> CastlingRights = CastlingRights::add_right;
+    let _: fn(&mut CastlingRights, Color, bool) = CastlingRights::revoke;
+    let _: fn(&mut CastlingRights, Color) = CastlingRights::revoke_all;
+    let _: fn(&CastlingRights, Color) -> CastlingRights = CastlingRights::color;
+    let _: fn(Color, Square) -> CastlingRights = CastlingRights::square_to_castle_rights;
+}
+
+#[test]
+fn test_can_castle() {
+    let mut castling_rights = CastlingRights::from_fen("Kq");
+
+    assert!(castling_rights.can_castle(Color::White, true));
+    assert!(!castling_rights.can_castle(Color::White, false));
+    assert!(!castling_rights.can_castle(Color::Black, true));
+    assert!(castling_rights.can_castle(Color::Black, false));
+
+    castling_rights.revoke(Color::White, true);
+    assert!(!castling_rights.can_castle(Color::White, true));
+
+    castling_rights.revoke_all(Color::Black);
+    assert_eq!(castling_rights, CastlingRights::new());
+}
+
+#[test]
+fn test_operators() {
+    let white = CastlingRights::from_fen("KQ");
+    let black = CastlingRights::from_fen("kq");
+
+    assert_eq!(white | black, CastlingRights::default());
+    assert_eq!(white & black, CastlingRights::new());
+    assert_eq!(!white, black);
+    assert_eq!(!CastlingRights::default(), CastlingRights::new());
+    assert_eq!((white | black).to_index(), 15);
+    assert_eq!(
+        !white & CastlingRights::from_fen("Kk"),
+        CastlingRights::from_fen("k")
+    );
+
+    assert!(CastlingRights::default().contains(white));
+    assert!((white | black).contains(CastlingRights::from_fen("Qk")));
+    assert!(!white.contains(CastlingRights::from_fen("Kk")));
+    assert!(!black.contains(white));
+    assert!(black.contains(CastlingRights::new()));
+
+    assert_eq!(
+        CastlingRights::default()
+            .remove(white)
+            .add(CastlingRights::from_fen("Q")),
+        CastlingRights::from_fen("Qkq")
+    );
+}
+
+#[test]
+fn test_from_fen() {
+    assert_eq!(CastlingRights::from_fen("-"), CastlingRights::new());
+    assert_eq!(CastlingRights::from_fen(""), CastlingRights::new());
+
+    let castling_rights = CastlingRights::from_fen("KQ");
+    assert!(castling_rights.can_castle(Color::White, true));
+    assert!(castling_rights.can_castle(Color::White, false));
+    assert_eq!(castling_rights.color(Color::Black), CastlingRights::new());
+    assert_eq!(castling_rights.to_fen(), "KQ");
+
+    // X-FEN names the file of the rook instead.
+    assert_eq!(
+        CastlingRights::from_fen("HAha"),
+        CastlingRights::from_fen("KQkq")
+    );
+    assert_eq!(
+        CastlingRights::from_fen("Hb"),
+        CastlingRights::from_fen("Kq")
+    );
+
+    // Unexpected characters don't add any rights.
+    assert_eq!(
+        CastlingRights::from_fen(" K q "),
+        CastlingRights::from_fen("Kq")
+    );
+    assert_eq!(CastlingRights::from_fen("0-0"), CastlingRights::new());
+    assert_eq!(CastlingRights::from_fen("Ee"), CastlingRights::new());
+}
@@ -1,4 +1,11 @@
-use chessframe::{board::Board, chess_move::ChessMove, piece::Piece, square::Square};
+use std::collections::BTreeSet;
+
+use chessframe::{
+    board::Board,
+    chess_move::ChessMove,
+    piece::Piece,
+    square::{SQUARES, Square},
+};
 
 #[test]
 fn test_pretty_print() {
@@ -40,6 +47,8 @@ fn test_make_null_move() {
         let inveted_board = Board::from_fen(inverted_fen);
 
         assert_eq!(board, inveted_board);
+        assert_eq!(board.pinned(), inveted_board.pinned());
+        assert_eq!(board.in_check(), inveted_board.in_check());
     }
 
     {
@@ -49,3 +58,69 @@ fn test_make_null_move() {
         assert!(board.make_null_move().is_err());
     }
 }
+
+#[test]
+fn test_u16_round_trip() {
+    let promotions = [
+        None,
+        Some(Piece::Knight),
+        Some(Piece::Bishop),
+        Some(Piece::Rook),
+        Some(Piece::Queen),
+    ];
+
+    let mut encoded = std::collections::HashSet::new();
+
+    for from in SQUARES {
+        for to in SQUARES {
+            for promotion in promotions {
+                let mv = ChessMove {
+                    from,
+                    to,
+                    promotion,
+                };
+                let value = mv.to_u16();
+
+                assert_eq!(value >> 15, 0);
+                assert_eq!(ChessMove::from_u16(value), mv);
+                assert!(encoded.insert(value));
+            }
+        }
+    }
+
+    assert_eq!(ChessMove::NULL_MOVE.to_u16(), 0);
+}
+
+#[test]
+fn test_ordering() {
+    let mut moves = vec![
+        ChessMove::new(Square::G1, Square::F3),
+        ChessMove::new_promotion(Square::A7, Square::A8, Piece::Queen),
+        ChessMove::new(Square::E2, Square::E4),
+        ChessMove::new_promotion(Square::A7, Square::A8, Piece::Knight),
+        ChessMove::new(Square::E2, Square::E3),
+        ChessMove::new(Square::A7, Square::A8),
+        ChessMove::new(Square::B1, Square::C3),
+    ];
+    moves.sort();
+
+    assert_eq!(
+        moves,
+        vec![
+            ChessMove::new(Square::B1, Square::C3),
+            ChessMove::new(Square::G1, Square::F3),
+            ChessMove::new(Square::E2, Square::E3),
+            ChessMove::new(Square::E2, Square::E4),
+            ChessMove::new(Square::A7, Square::A8),
+            ChessMove::new_promotion(Square::A7, Square::A8, Piece::Knight),
+            ChessMove::new_promotion(Square::A7, Square::A8, Piece::Queen),
+        ]
+    );
+
+    let set: BTreeSet<ChessMove> = moves.iter().rev().copied().collect();
+    assert!(set.into_iter().eq(moves));
+
+    assert!(Square::A1 < Square::H1);
+    assert!(Square::H1 < Square::A2);
+    assert_eq!(SQUARES.iter().max(), Some(&Square::H8));
+}
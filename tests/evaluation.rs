@@ -0,0 +1,42 @@
+use chessframe::{
+    board::Board,
+    evaluation::{Evaluator, MaterialEvaluator},
+};
+
+#[test]
+fn test_material_evaluator() {
+    assert_eq!(MaterialEvaluator.evaluate(&Board::default()), 0);
+
+    let board = Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1");
+    let score = MaterialEvaluator.evaluate(&board);
+    assert!(score < 0);
+    assert_eq!(
+        MaterialEvaluator.evaluate(&board.make_null_move_new().unwrap()),
+        -score
+    );
+
+    // A mirrored position scores the same for the side to move.
+    let white = Board::from_fen("4k3/8/8/8/3N4/8/8/4K3 w - - 0 1");
+    let black = Board::from_fen("4k3/8/8/3n4/8/8/8/4K3 b - - 0 1");
+    assert_eq!(
+        MaterialEvaluator.evaluate(&white),
+        MaterialEvaluator.evaluate(&black)
+    );
+
+    let closure = |board: &Board| board.half_moves as i32;
+    assert_eq!(closure.evaluate(&Board::default()), 0);
+}
+
+#[test]
+fn test_stand_pat() {
+    assert_eq!(Board::default().stand_pat(&MaterialEvaluator), 0);
+
+    let board = Board::from_fen("4k3/8/8/8/3N4/8/8/4K3 w - - 0 1");
+    assert_eq!(
+        board.stand_pat(&MaterialEvaluator),
+        MaterialEvaluator.evaluate(&board)
+    );
+
+    let closure = |board: &Board| board.half_moves as i32;
+    assert_eq!(board.stand_pat(&closure), 0);
+}
@@ -0,0 +1,148 @@
+use chessframe::{
+    chess_move::ChessMove,
+    color::Color,
+    error::Error,
+    game::{Event, Game, GameResult},
+    square::Square,
+};
+
+#[test]
+fn test_draw_by_agreement() {
+    let mut game = Game::new();
+
+    assert_eq!(game.draw_by_agreement(), Ok(()));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw(Event::DrawByAgreement))
+    );
+
+    assert_eq!(game.draw_by_agreement(), Err(Error::GameEnded));
+    assert_eq!(
+        game.play_move(ChessMove::new(Square::E2, Square::E4)),
+        Err(Error::GameEnded)
+    );
+}
+
+#[test]
+fn test_adjudicate() {
+    let mut game = Game::new();
+
+    assert_eq!(game.adjudicate(1), Ok(false));
+    assert_eq!(game.result(), None);
+
+    // Reaching the limit is still fine, going over it isn't.
+    let _ = game.play_move(ChessMove::new(Square::G1, Square::F3));
+    assert_eq!(game.adjudicate(1), Ok(false));
+    assert_eq!(game.result(), None);
+
+    let _ = game.play_move(ChessMove::new(Square::G8, Square::F6));
+    assert_eq!(game.adjudicate(1), Ok(true));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw(Event::DrawByAdjudication))
+    );
+
+    assert_eq!(game.adjudicate(1), Err(Error::GameEnded));
+}
+
+#[test]
+fn test_result() {
+    let mut game = Game::from_fen("7k/7p/7K/5Q2/8/8/8/8 w - - 0 1");
+    let _ = game.play_move(ChessMove::new(Square::F5, Square::F8));
+    assert_eq!(game.result(), Some(GameResult::Win(Color::White)));
+
+    let mut game = Game::from_fen("7k/7p/7K/5Q2/8/8/8/8 w - - 0 1");
+    let _ = game.play_move(ChessMove::new(Square::F5, Square::F7));
+    assert_eq!(game.result(), Some(GameResult::Draw(Event::Stalemate)));
+
+    let mut game = Game::new();
+    let _ = game.timeout(Color::White);
+    assert_eq!(game.result(), Some(GameResult::Win(Color::Black)));
+}
+
+#[test]
+fn test_san_history() {
+    let mut game = Game::new();
+
+    for (from, to) in [
+        (Square::E2, Square::E4),
+        (Square::E7, Square::E5),
+        (Square::G1, Square::F3),
+        (Square::B8, Square::C6),
+        (Square::F1, Square::C4),
+        (Square::G8, Square::F6),
+        (Square::B1, Square::C3),
+        (Square::F8, Square::C5),
+        (Square::E1, Square::G1),
+        (Square::D7, Square::D6),
+        (Square::D2, Square::D3),
+        (Square::C8, Square::G4),
+        (Square::C4, Square::F7),
+    ] {
+        game.play_move(ChessMove::new(from, to)).unwrap();
+    }
+
+    assert_eq!(
+        game.san_history(),
+        vec![
+            "e4", "e5", "Nf3", "Nc6", "Bc4", "Nf6", "Nc3", "Bc5", "O-O", "d6", "d3", "Bg4", "Bxf7+"
+        ]
+    );
+
+    // Scholar's mate.
+    let mut game = Game::new();
+    for (from, to) in [
+        (Square::E2, Square::E4),
+        (Square::E7, Square::E5),
+        (Square::D1, Square::H5),
+        (Square::B8, Square::C6),
+        (Square::F1, Square::C4),
+        (Square::G8, Square::F6),
+        (Square::H5, Square::F7),
+    ] {
+        game.play_move(ChessMove::new(from, to)).unwrap();
+    }
+
+    assert_eq!(
+        game.san_history(),
+        vec!["e4", "e5", "Qh5", "Nc6", "Bc4", "Nf6", "Qxf7#"]
+    );
+}
+
+#[test]
+fn test_fen() {
+    let mut game = Game::new();
+
+    assert_eq!(
+        game.fen(),
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    );
+
+    game.play_move(ChessMove::new(Square::G1, Square::F3))
+        .unwrap();
+    game.play_move(ChessMove::new(Square::G8, Square::F6))
+        .unwrap();
+    game.play_move(ChessMove::new(Square::F3, Square::G1))
+        .unwrap();
+
+    assert_eq!(
+        game.fen(),
+        "rnbqkb1r/pppppppp/5n2/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 3 2"
+    );
+
+    let game = Game::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+    assert_eq!(game.fen(), "4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+    // The fullmove number of the FEN is kept, and goes up after Black moves.
+    let mut game = Game::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 40");
+    assert_eq!(game.fen(), "4k3/8/8/8/8/8/8/4K3 b - - 0 40");
+
+    game.play_move(ChessMove::new(Square::E8, Square::D8))
+        .unwrap();
+    assert_eq!(game.fen(), "3k4/8/8/8/8/8/8/4K3 w - - 1 41");
+
+    game.play_move(ChessMove::new(Square::E1, Square::D1))
+        .unwrap();
+    assert_eq!(game.fen(), "3k4/8/8/8/8/8/8/3K4 b - - 2 41");
+}
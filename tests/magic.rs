@@ -0,0 +1,106 @@
+use chessframe::{
+    bitboard::{BitBoard, EMPTY},
+    magic::{get_bishop_moves, get_rook_moves, magic_table_bytes},
+    square::{SQUARES, Square},
+};
+use rand_chacha::{
+    ChaCha8Rng,
+    rand_core::{Rng, SeedableRng},
+};
+
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+fn slider_moves(square: Square, blockers: BitBoard, directions: [(i8, i8); 4]) -> BitBoard {
+    let mut moves = EMPTY;
+
+    for (rank_step, file_step) in directions {
+        let mut rank = square.rank().to_index() as i8 + rank_step;
+        let mut file = square.file().to_index() as i8 + file_step;
+
+        while (0..8).contains(&rank) && (0..8).contains(&file) {
+            let current = Square::new((rank * 8 + file) as u8);
+            moves |= BitBoard::from_square(current);
+
+            if blockers.is_set(current) {
+                break;
+            }
+
+            rank += rank_step;
+            file += file_step;
+        }
+    }
+
+    moves
+}
+
+fn occupancies() -> Vec<BitBoard> {
+    let mut rng = ChaCha8Rng::seed_from_u64(0xC0FFEE);
+
+    let mut occupancies = vec![EMPTY, !EMPTY];
+    for _ in 0..256 {
+        occupancies.push(BitBoard(rng.next_u64()));
+        occupancies.push(BitBoard(rng.next_u64() & rng.next_u64()));
+        occupancies.push(BitBoard(rng.next_u64() & rng.next_u64() & rng.next_u64()));
+    }
+
+    occupancies
+}
+
+#[test]
+fn test_bishop_moves() {
+    let occupancies = occupancies();
+
+    for square in SQUARES {
+        for blockers in occupancies.iter() {
+            assert_eq!(
+                get_bishop_moves(square, *blockers),
+                slider_moves(square, *blockers, BISHOP_DIRECTIONS),
+                "{} {:?}",
+                square,
+                blockers
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rook_moves() {
+    let occupancies = occupancies();
+
+    for square in SQUARES {
+        for blockers in occupancies.iter() {
+            assert_eq!(
+                get_rook_moves(square, *blockers),
+                slider_moves(square, *blockers, ROOK_DIRECTIONS),
+                "{} {:?}",
+                square,
+                blockers
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rook_moves_unchanged() {
+    assert_eq!(
+        get_rook_moves(Square::A1, EMPTY),
+        BitBoard(0x01010101010101FE)
+    );
+    assert_eq!(
+        get_rook_moves(Square::D4, EMPTY),
+        BitBoard(0x08080808F7080808)
+    );
+    assert_eq!(
+        get_rook_moves(Square::D4, BitBoard(0x0008000020000000)),
+        BitBoard(0x0008080837080808)
+    );
+}
+
+#[test]
+fn test_magic_table_bytes() {
+    let bytes = magic_table_bytes();
+
+    assert!(bytes > 64 * 64 * size_of::<BitBoard>());
+    assert!(bytes < 2_500_000);
+}
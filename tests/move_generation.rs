@@ -1,8 +1,26 @@
 use chessframe::{
     bitboard::{BitBoard, EMPTY},
     board::*,
+    chess_move::ChessMove,
+    color::Color,
+    movegen::MoveGen,
+    piece::Piece,
+    square::{SQUARES, Square},
 };
 
+/// Perft positions plus a few en passant and pin edge cases, shared by the tests that compare
+/// different move generators against each other.
+const TEST_FENS: [&str; 8] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    "8/8/8/K2Pp2q/8/8/8/7k w - e6 0 1",
+    "8/8/8/8/k2Pp2Q/8/8/3K4 b - d3 0 1",
+    "4r2k/8/8/8/1b6/8/8/4K3 w - - 0 1",
+];
+
 #[test]
 fn test_generate_moves_vec() {
     let fen = "8/p7/1k2Pp2/1P3P2/1K6/8/8/8 w - - 0 1";
@@ -12,6 +30,42 @@ fn test_generate_moves_vec() {
     assert_eq!(moves.len(), 8)
 }
 
+#[test]
+fn test_generate_moves_mask() {
+    let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let board = Board::from_fen(fen);
+
+    let all_moves = board.generate_moves_vec(!EMPTY);
+
+    for square in SQUARES {
+        let mask = BitBoard::from_square(square);
+
+        let moves = board.generate_moves_vec(mask);
+        assert!(moves.iter().all(|mv| mv.to == square));
+        assert_eq!(
+            moves.len(),
+            all_moves.iter().filter(|mv| mv.to == square).count()
+        );
+
+        let mut buffer = [ChessMove::NULL_MOVE; 96];
+        let count = board.generate_moves(mask, &mut buffer);
+        assert_eq!(&buffer[..count], &moves[..]);
+    }
+
+    // Quiet single and double pawn pushes respect the mask.
+    let moves = board.generate_moves_vec(BitBoard::from_square(Square::A3));
+    assert_eq!(moves, vec![ChessMove::new(Square::A2, Square::A3)]);
+
+    let moves = board.generate_moves_vec(BitBoard::from_square(Square::A4));
+    assert_eq!(
+        moves,
+        vec![
+            ChessMove::new(Square::C3, Square::A4),
+            ChessMove::new(Square::A2, Square::A4)
+        ]
+    );
+}
+
 #[test]
 fn test_pawn_move_generation() {
     // Test pawn move generation from a starting position
@@ -229,3 +283,435 @@ fn test_castling_move_generation() {
         assert_eq!(castling_moves, BitBoard(0x40));
     }
 }
+
+#[test]
+fn test_pawns_on_last_rank() {
+    let board = Board::from_fen("3P3k/8/8/8/8/8/8/K7 w - - 0 1");
+    let pawn_moves: Vec<ChessMove> = board
+        .generate_moves_vec(!EMPTY)
+        .into_iter()
+        .filter(|mv| mv.from == Square::D8)
+        .collect();
+
+    assert!(pawn_moves.is_empty());
+    assert_eq!(board.generate_pawn_moves(), EMPTY);
+
+    let mut moves = [ChessMove::NULL_MOVE; 256];
+    let count = board.generate_moves(!EMPTY, &mut moves);
+    assert!(moves[..count].iter().all(|mv| mv.from != Square::D8));
+
+    let board = Board::from_fen("7k/8/8/8/8/8/8/K2p4 b - - 0 1");
+    assert_eq!(board.generate_pawn_moves(), EMPTY);
+}
+
+#[test]
+fn test_en_passant_pin() {
+    let en_passant = ChessMove::new(Square::D5, Square::E6);
+
+    let board = Board::from_fen("8/8/8/K2Pp2q/8/8/8/7k w - e6 0 1");
+    assert_eq!(board.en_passant_square(), Some(Square::E6));
+    assert!(board.generate_moves_vec(!EMPTY).contains(&en_passant));
+    assert!(board.make_move_new(en_passant).is_err());
+    assert!(board.make_move_new_metadata(en_passant).is_err());
+    assert!(!board.legal_moves().contains(&en_passant));
+
+    let board = Board::from_fen("8/8/8/K2Pp3/8/8/8/7k w - e6 0 1");
+    assert!(board.make_move_new(en_passant).is_ok());
+    assert!(board.legal_moves().contains(&en_passant));
+
+    let board = Board::from_fen("8/8/8/K2Pp2q/8/8/8/7k w - - 0 1");
+    assert_eq!(board.en_passant_square(), None);
+
+    let board = Board::from_fen("7K/8/8/8/Q2Pp2k/8/8/8 b - d3 0 1");
+    let en_passant = ChessMove::new(Square::E4, Square::D3);
+    assert!(board.make_move_new(en_passant).is_err());
+    assert!(!board.legal_moves().contains(&en_passant));
+}
+
+#[test]
+fn test_en_passant_diagonal_pin() {
+    // Removing the captured pawn on d5 opens the diagonal from the bishop to the king.
+    let board = Board::from_fen("4k3/1b6/8/3pP3/8/5K2/8/8 w - d6 0 1");
+    let en_passant = ChessMove::new(Square::E5, Square::D6);
+
+    assert_eq!(board.en_passant_square(), Some(Square::D6));
+    assert!(board.make_move_new(en_passant).is_err());
+    assert!(!board.legal_moves().contains(&en_passant));
+    assert!(board.make_move_new_metadata(en_passant).is_err());
+
+    // The rejected capture leaves the board untouched.
+    let mut rejected = board;
+    assert!(rejected.make_move(en_passant).is_err());
+    assert_eq!(rejected.to_fen(), board.to_fen());
+    assert_eq!(rejected.hash(), board.hash());
+    assert_eq!(rejected.in_check(), board.in_check());
+
+    let mut rejected = board;
+    assert!(rejected.make_move_metadata(en_passant).is_err());
+    assert_eq!(rejected.to_fen(), board.to_fen());
+    assert_eq!(rejected.hash(), board.hash());
+    assert_eq!(rejected.in_check(), board.in_check());
+    assert_eq!(rejected.half_moves, board.half_moves);
+
+    let board = Board::from_fen("4k3/8/8/3pP3/8/5K2/8/8 w - d6 0 1");
+    assert!(board.legal_moves().contains(&en_passant));
+}
+
+#[test]
+fn test_generate_moves_with() {
+    let board = Board::from_fen("2n1k3/1P6/8/8/8/8/8/4K3 w - - 0 1");
+
+    let options = GenOptions {
+        underpromotions: false,
+        ..GenOptions::default()
+    };
+    let promotions: Vec<ChessMove> = board
+        .generate_moves_with(!EMPTY, options)
+        .into_iter()
+        .filter(|mv| mv.promotion().is_some())
+        .collect();
+    assert_eq!(
+        promotions,
+        vec![
+            ChessMove::new_promotion(Square::B7, Square::B8, Piece::Queen),
+            ChessMove::new_promotion(Square::B7, Square::C8, Piece::Queen),
+        ]
+    );
+
+    assert_eq!(
+        board.generate_moves_with(!EMPTY, GenOptions::default()),
+        board.generate_moves_vec(!EMPTY)
+    );
+
+    let captures = GenOptions {
+        quiets: false,
+        ..GenOptions::default()
+    };
+    let moves = board.generate_moves_with(!EMPTY, captures);
+    assert_eq!(moves.len(), 4);
+    assert!(moves.iter().all(|mv| mv.to == Square::C8));
+
+    let quiets = GenOptions {
+        captures: false,
+        ..GenOptions::default()
+    };
+    let moves = board.generate_moves_with(!EMPTY, quiets);
+    assert!(moves.iter().all(|mv| mv.to != Square::C8));
+    assert_eq!(moves.len() + 4, board.generate_moves_vec(!EMPTY).len());
+
+    let board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1");
+    let en_passant = ChessMove::new(Square::E5, Square::D6);
+    assert!(
+        board
+            .generate_moves_with(!EMPTY, captures)
+            .contains(&en_passant)
+    );
+    assert!(
+        !board
+            .generate_moves_with(!EMPTY, quiets)
+            .contains(&en_passant)
+    );
+}
+
+#[test]
+fn test_count_legal_moves() {
+    for fen in TEST_FENS
+        .into_iter()
+        .chain(["R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1"])
+    {
+        let board = Board::from_fen(fen);
+        assert_eq!(
+            board.count_legal_moves(),
+            board.legal_moves().len(),
+            "{}",
+            fen
+        );
+    }
+
+    assert_eq!(
+        Board::from_fen("R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1").count_legal_moves(),
+        0
+    );
+}
+
+#[test]
+fn test_is_pseudo_legal() {
+    let board = Board::default();
+    assert!(board.is_pseudo_legal(ChessMove::new(Square::E2, Square::E4)));
+    assert!(!board.is_pseudo_legal(ChessMove::new(Square::E7, Square::E5)));
+    assert!(!board.is_pseudo_legal(ChessMove::new(Square::E4, Square::E5)));
+    assert!(!board.is_pseudo_legal(ChessMove::new(Square::A1, Square::A3)));
+
+    for fen in TEST_FENS
+        .into_iter()
+        .chain(["r3k2r/p1ppqpb1/bn2pnp1/3PN3/Pp2P3/2N2Q1p/1PPBBPPP/R3K2R b KQkq a3 0 1"])
+    {
+        let board = Board::from_fen(fen);
+        let moves = board.generate_moves_vec(!EMPTY);
+
+        for from in SQUARES {
+            for to in SQUARES {
+                for promotion in [None, Some(Piece::Knight), Some(Piece::Queen)] {
+                    let mv = ChessMove {
+                        from,
+                        to,
+                        promotion,
+                    };
+
+                    assert_eq!(
+                        board.is_pseudo_legal(mv),
+                        moves.contains(&mv),
+                        "{} {}",
+                        fen,
+                        mv
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generate_legal_into() {
+    fn check(board: &Board, depth: usize) {
+        let mut moves = Vec::new();
+        board.generate_legal_into(&mut moves);
+        moves.sort();
+
+        let mut expected = board.legal_moves();
+        expected.sort();
+
+        assert_eq!(moves, expected, "{}", board.to_fen());
+
+        if depth > 1 {
+            for mv in expected {
+                check(&board.make_move_new(mv).unwrap(), depth - 1);
+            }
+        }
+    }
+
+    for fen in TEST_FENS {
+        check(&Board::from_fen(fen), 3);
+    }
+}
+
+#[test]
+fn test_attacked_squares_excluding_king() {
+    let board = Board::from_fen("7k/8/8/8/8/8/8/r3K3 w - - 0 1");
+    let attacked = board.attacked_squares_excluding_king();
+
+    assert!(attacked.is_set(Square::D1));
+    assert!(attacked.is_set(Square::F1));
+    assert!(attacked.is_set(Square::H1));
+    assert!(!attacked.is_set(Square::E2));
+
+    let mut moves = Vec::new();
+    board.generate_legal_into(&mut moves);
+    moves.sort();
+
+    assert_eq!(
+        moves,
+        vec![
+            ChessMove::new(Square::E1, Square::D2),
+            ChessMove::new(Square::E1, Square::E2),
+            ChessMove::new(Square::E1, Square::F2),
+        ]
+    );
+}
+
+#[test]
+fn test_double_push_blocked() {
+    // A knight on the fourth rank blocks the double push, but not the single push.
+    for fen in [
+        "4k3/8/8/8/4n3/8/4P3/4K3 w - - 0 1",
+        "4k3/4p3/8/4N3/8/8/8/4K3 b - - 0 1",
+    ] {
+        let board = Board::from_fen(fen);
+        let (from, single, double) = match board.side_to_move {
+            Color::White => (Square::E2, Square::E3, Square::E4),
+            Color::Black => (Square::E7, Square::E6, Square::E5),
+        };
+
+        let pushes: Vec<Square> = board
+            .generate_moves_vec(!EMPTY)
+            .into_iter()
+            .filter(|mv| mv.from == from)
+            .map(|mv| mv.to)
+            .collect();
+        assert_eq!(pushes, vec![single], "{}", fen);
+
+        let mut moves = [ChessMove::NULL_MOVE; 64];
+        let count = board.generate_moves(!EMPTY, &mut moves);
+        assert!(!moves[..count].contains(&ChessMove::new(from, double)));
+
+        let mut moves = Vec::new();
+        board.generate_legal_into(&mut moves);
+        assert!(moves.contains(&ChessMove::new(from, single)));
+        assert!(!moves.contains(&ChessMove::new(from, double)));
+
+        assert!(board.generate_pawn_moves().is_set(single));
+        assert!(!board.generate_pawn_moves().is_set(double));
+        assert!(!board.is_pseudo_legal(ChessMove::new(from, double)));
+    }
+}
+
+#[test]
+fn test_pawn_pushes_and_captures() {
+    let board = Board::default();
+
+    assert_eq!(
+        board.pawn_pushes(Color::White),
+        BitBoard(0x00000000FFFF0000)
+    );
+    assert_eq!(
+        board.pawn_pushes(Color::Black),
+        BitBoard(0x0000FFFF00000000)
+    );
+    assert_eq!(board.pawn_captures(Color::White), EMPTY);
+    assert_eq!(board.pawn_captures(Color::Black), EMPTY);
+
+    let board = board
+        .make_move_new(ChessMove::new(Square::E2, Square::E4))
+        .unwrap()
+        .make_move_new(ChessMove::new(Square::D7, Square::D5))
+        .unwrap();
+
+    assert!(board.pawn_pushes(Color::White).is_set(Square::E5));
+    assert!(!board.pawn_pushes(Color::White).is_set(Square::E3));
+    assert!(board.pawn_pushes(Color::Black).is_set(Square::D4));
+    assert_eq!(
+        board.pawn_captures(Color::White),
+        BitBoard::from_square(Square::D5)
+    );
+    assert_eq!(
+        board.pawn_captures(Color::Black),
+        BitBoard::from_square(Square::E4)
+    );
+    assert_eq!(
+        board.generate_pawn_moves(),
+        board.pawn_pushes(Color::White) | board.pawn_captures(Color::White)
+    );
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_generate_moves_arrayvec() {
+    fn check(board: &Board, depth: usize) {
+        for mask in [!EMPTY, board.occupancy(!board.side_to_move)] {
+            assert_eq!(
+                board.generate_moves_arrayvec(mask).as_slice(),
+                board.generate_moves_vec(mask).as_slice(),
+                "{}",
+                board.to_fen()
+            );
+        }
+
+        if depth > 0 {
+            for mv in board.legal_moves() {
+                check(&board.make_move_new(mv).unwrap(), depth - 1);
+            }
+        }
+    }
+
+    for fen in TEST_FENS {
+        check(&Board::from_fen(fen), 2);
+    }
+}
+
+#[test]
+fn test_movegen_with_see() {
+    // The knight can take the rook or the pawn, both defended by the pawn on c6.
+    let board = Board::from_fen("4k3/8/2p5/1r1p4/8/2N5/8/4K3 w - - 0 1");
+    let winning = ChessMove::new(Square::C3, Square::B5);
+    let losing = ChessMove::new(Square::C3, Square::D5);
+
+    let moves: Vec<ChessMove> = MoveGen::with_see(&board).collect();
+    assert_eq!(moves.first(), Some(&winning));
+    assert_eq!(moves.last(), Some(&losing));
+
+    // The quiet moves follow in the order they were generated in.
+    let quiet_moves: Vec<ChessMove> = MoveGen::new(&board)
+        .filter(|mv| *mv != winning && *mv != losing)
+        .collect();
+    assert_eq!(moves[1..moves.len() - 1], quiet_moves);
+
+    let mut sorted = moves.clone();
+    sorted.sort();
+    let mut legal_moves = board.legal_moves();
+    legal_moves.sort();
+    assert_eq!(sorted, legal_moves);
+
+    let mut moves = MoveGen::with_see(&board);
+    assert_eq!(moves.len(), legal_moves.len());
+    moves.next();
+    assert_eq!(moves.len(), legal_moves.len() - 1);
+
+    let moves: Vec<ChessMove> = MoveGen::new(&board).collect();
+    assert_eq!(moves.len(), legal_moves.len());
+}
+
+#[test]
+fn test_legal_moves_from() {
+    let board = Board::default();
+    assert_eq!(
+        board.legal_moves_from(Square::E2),
+        vec![
+            ChessMove::new(Square::E2, Square::E3),
+            ChessMove::new(Square::E2, Square::E4)
+        ]
+    );
+    assert!(board.legal_moves_from(Square::E7).is_empty());
+    assert!(board.legal_moves_from(Square::E4).is_empty());
+
+    for fen in TEST_FENS
+        .into_iter()
+        .chain(["4k3/4r3/8/8/8/8/4B3/4K3 w - - 0 1"])
+    {
+        let board = Board::from_fen(fen);
+        let legal_moves = board.legal_moves();
+
+        for square in SQUARES {
+            let mut moves = board.legal_moves_from(square);
+            moves.sort();
+
+            let mut expected: Vec<ChessMove> = legal_moves
+                .iter()
+                .copied()
+                .filter(|mv| mv.get_move().0 == square)
+                .collect();
+            expected.sort();
+
+            assert_eq!(moves, expected, "{fen} {square}");
+        }
+    }
+}
+
+#[test]
+fn test_legal_destinations() {
+    let board = Board::default();
+    assert_eq!(
+        board.legal_destinations(Square::B1),
+        BitBoard::from_square(Square::A3) | BitBoard::from_square(Square::C3)
+    );
+    assert_eq!(board.legal_destinations(Square::D1), EMPTY);
+    assert_eq!(board.legal_destinations(Square::B8), EMPTY);
+    assert_eq!(board.legal_destinations(Square::E4), EMPTY);
+
+    // The pinned bishop can't move, and the king can't step onto the e-file.
+    let board = Board::from_fen("4k3/4r3/8/8/8/8/4B3/4K3 w - - 0 1");
+    assert_eq!(board.legal_destinations(Square::E2), EMPTY);
+    assert_eq!(
+        board.legal_destinations(Square::E1),
+        BitBoard::from_square(Square::D1)
+            | BitBoard::from_square(Square::F1)
+            | BitBoard::from_square(Square::D2)
+            | BitBoard::from_square(Square::F2)
+    );
+
+    // All four promotions land on the same square.
+    let board = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1");
+    assert_eq!(
+        board.legal_destinations(Square::B7),
+        BitBoard::from_square(Square::B8)
+    );
+}
@@ -28,6 +28,32 @@ impl Perft {
         count
     }
 
+    fn perft_fast(&mut self, board: &Board, depth: usize, divide: bool) -> usize {
+        let mut count = 0;
+
+        let num_moves = board.generate_moves(!EMPTY, &mut self.0[depth]);
+
+        for i in 0..num_moves {
+            let mv = self.0[depth][i];
+            let mut board = *board;
+
+            if board.make_move_fast(mv).is_ok() {
+                let perft_results = if depth == 1 {
+                    1
+                } else {
+                    self.perft_fast(&board, depth - 1, false)
+                };
+                count += perft_results;
+
+                if divide {
+                    println!("{}: {}", mv, perft_results);
+                }
+            }
+        }
+
+        count
+    }
+
     fn perft_unmake(&mut self, board: &mut Board, depth: usize, divide: bool) -> usize {
         let mut count = 0;
 
@@ -58,12 +84,49 @@ impl Perft {
     }
 }
 
+struct LegalPerft(Vec<Vec<ChessMove>>);
+
+impl LegalPerft {
+    fn perft(&mut self, board: &mut Board, depth: usize, divide: bool) -> usize {
+        let mut count = 0;
+
+        let unmake_data = board.unmake_data();
+
+        let mut moves = std::mem::take(&mut self.0[depth]);
+        moves.clear();
+        board.generate_legal_into(&mut moves);
+
+        for &mv in &moves {
+            let metadata = board.make_move_metadata(mv).unwrap();
+
+            let perft_results = if depth == 1 {
+                1
+            } else {
+                self.perft(board, depth - 1, false)
+            };
+            count += perft_results;
+
+            board.unmake_move(mv, metadata, unmake_data).unwrap();
+
+            if divide {
+                println!("{}: {}", mv, perft_results);
+            }
+        }
+
+        self.0[depth] = moves;
+
+        count
+    }
+}
+
 trait PerftImpl {
     fn run(board: &Board, depth: usize, divide: bool) -> usize;
 }
 
 struct MakeNew;
+struct MakeFast;
 struct Unmake;
+struct Legal;
 
 impl PerftImpl for MakeNew {
     fn run(board: &Board, depth: usize, divide: bool) -> usize {
@@ -74,6 +137,15 @@ impl PerftImpl for MakeNew {
     }
 }
 
+impl PerftImpl for MakeFast {
+    fn run(board: &Board, depth: usize, divide: bool) -> usize {
+        let mut perft = Perft([[ChessMove::NULL_MOVE; 96]; 10]);
+        let board = *board;
+
+        perft.perft_fast(&board, depth, divide)
+    }
+}
+
 impl PerftImpl for Unmake {
     fn run(board: &Board, depth: usize, divide: bool) -> usize {
         let mut perft = Perft([[ChessMove::NULL_MOVE; 96]; 10]);
@@ -83,6 +155,15 @@ impl PerftImpl for Unmake {
     }
 }
 
+impl PerftImpl for Legal {
+    fn run(board: &Board, depth: usize, divide: bool) -> usize {
+        let mut perft = LegalPerft((0..10).map(|_| Vec::with_capacity(96)).collect());
+        let mut board = *board;
+
+        perft.perft(&mut board, depth, divide)
+    }
+}
+
 fn perft_test<T: PerftImpl>(fen: &str, depth: usize, expected: usize) {
     let board = Board::from_fen(fen);
     assert_eq!(T::run(&board, depth, false), expected);
@@ -195,3 +276,48 @@ macro_rules! generate_perft_tests {
 
 generate_perft_tests!(make_new, MakeNew);
 generate_perft_tests!(unmake, Unmake);
+
+#[test]
+fn test_perft_depth_5_make_fast() {
+    perft_test::<MakeFast>(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        5,
+        4865609,
+    );
+}
+
+#[test]
+fn test_perft_kiwipete_make_fast() {
+    perft_test::<MakeFast>(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        3,
+        97862,
+    );
+}
+
+#[test]
+fn test_perft_depth_5_legal() {
+    perft_test::<Legal>(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        5,
+        4865609,
+    );
+}
+
+#[test]
+fn test_perft_kiwipete_legal() {
+    perft_test::<Legal>(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        3,
+        97862,
+    );
+}
+
+#[test]
+fn test_perft_position_5_legal() {
+    perft_test::<Legal>(
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        3,
+        62379,
+    );
+}
@@ -0,0 +1,20 @@
+use chessframe::{
+    error::Error,
+    piece::{PIECES, Piece},
+};
+
+#[test]
+fn test_from_str() {
+    assert_eq!("q".parse(), Ok(Piece::Queen));
+    assert_eq!("Q".parse(), Ok(Piece::Queen));
+    assert_eq!("Queen".parse(), Ok(Piece::Queen));
+    assert_eq!("KNIGHT".parse(), Ok(Piece::Knight));
+
+    for piece in PIECES {
+        assert_eq!(piece.to_fen().to_string().parse(), Ok(piece));
+    }
+
+    assert_eq!("x".parse::<Piece>(), Err(Error::InvalidPiece));
+    assert_eq!("".parse::<Piece>(), Err(Error::InvalidPiece));
+    assert_eq!("queens".parse::<Piece>(), Err(Error::InvalidPiece));
+}
@@ -0,0 +1,41 @@
+use chessframe::score::*;
+
+#[test]
+fn test_mate_scores() {
+    assert_eq!(mate_in(0), MATE_VALUE);
+    assert_eq!(mated_in(0), -MATE_VALUE);
+    assert!(mate_in(1) > mate_in(5));
+    assert!(mated_in(1) < mated_in(5));
+
+    assert!(is_mate_score(mate_in(MAX_PLY)));
+    assert!(is_mate_score(mated_in(MAX_PLY)));
+    assert!(!is_mate_score(mate_in(MAX_PLY + 1)));
+    assert!(!is_mate_score(DRAW_SCORE));
+    assert_eq!(mate_in(MAX_PLY), MATE_IN_MAX_PLY);
+}
+
+#[test]
+fn test_table_round_trip() {
+    for ply in [0, 1, 7, 40] {
+        for score in [
+            DRAW_SCORE,
+            250,
+            -1300,
+            mate_in(ply + 3),
+            mated_in(ply + 8),
+            mate_in(ply),
+            mated_in(ply),
+        ] {
+            assert_eq!(score_from_table(score_to_table(score, ply), ply), score);
+        }
+    }
+
+    // A mate found at one ply is the same distance from the position when found at another.
+    let stored = score_to_table(mate_in(9), 4);
+    assert_eq!(stored, mate_in(5));
+    assert_eq!(score_from_table(stored, 6), mate_in(11));
+
+    let stored = score_to_table(mated_in(6), 2);
+    assert_eq!(stored, mated_in(4));
+    assert_eq!(score_from_table(stored, 1), mated_in(5));
+}
@@ -0,0 +1,72 @@
+use chessframe::{
+    board::Board,
+    chess_move::ChessMove,
+    color::Color,
+    evaluation::MaterialEvaluator,
+    piece::PIECES,
+    score::MATE_VALUE,
+    search::negamax,
+    square::Square,
+};
+
+fn material(board: &Board) -> i32 {
+    let score: i32 = PIECES
+        .iter()
+        .map(|piece| {
+            let white = board.pieces_color(*piece, Color::White).count_ones() as i32;
+            let black = board.pieces_color(*piece, Color::Black).count_ones() as i32;
+
+            (white - black) * piece.value()
+        })
+        .sum();
+
+    match board.side_to_move {
+        Color::White => score,
+        Color::Black => -score,
+    }
+}
+
+#[test]
+fn test_mate_in_one() {
+    let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1");
+    let (score, best_move) = negamax(&board, 3, material);
+
+    assert_eq!(best_move, Some(ChessMove::new(Square::A1, Square::A8)));
+    assert_eq!(score, MATE_VALUE - 1);
+
+    let board = Board::from_fen("r5k1/8/8/8/8/8/5PPP/6K1 b - - 0 1");
+    let (score, best_move) = negamax(&board, 2, material);
+
+    assert_eq!(best_move, Some(ChessMove::new(Square::A8, Square::A1)));
+    assert_eq!(score, MATE_VALUE - 1);
+}
+
+#[test]
+fn test_no_legal_moves() {
+    // Checkmated.
+    let board = Board::from_fen("R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1");
+    assert_eq!(negamax(&board, 2, material), (-MATE_VALUE, None));
+
+    // Stalemate.
+    let board = Board::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1");
+    assert_eq!(negamax(&board, 2, material), (0, None));
+}
+
+#[test]
+fn test_wins_material() {
+    // The knight on d5 is hanging.
+    let board = Board::from_fen("4k3/8/8/3n4/8/8/3R4/4K3 w - - 0 1");
+    let (score, best_move) = negamax(&board, 2, material);
+
+    assert_eq!(best_move, Some(ChessMove::new(Square::D2, Square::D5)));
+    assert_eq!(score, 500);
+}
+
+#[test]
+fn test_material_evaluator() {
+    let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1");
+    let (score, best_move) = negamax(&board, 2, MaterialEvaluator);
+
+    assert_eq!(best_move, Some(ChessMove::new(Square::A1, Square::A8)));
+    assert_eq!(score, MATE_VALUE - 1);
+}
@@ -0,0 +1,171 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chessframe::{
+    color::Color,
+    direction::{DIRECTIONS, Direction},
+    error::Error,
+    file::File,
+    magic::{CENTER_DISTANCE, center_distance},
+    rank::Rank,
+    square::{SQUARES, Square},
+};
+
+#[test]
+fn test_knight_distance() {
+    assert_eq!(Square::B1.knight_distance(Square::C3), 1);
+    assert_eq!(Square::A1.knight_distance(Square::A1), 0);
+    assert_eq!(Square::A1.knight_distance(Square::B2), 4);
+    assert_eq!(Square::A1.knight_distance(Square::H8), 6);
+}
+
+#[test]
+fn test_knight_distance_symmetric() {
+    for a in SQUARES {
+        for b in SQUARES {
+            assert_eq!(a.knight_distance(b), b.knight_distance(a));
+        }
+    }
+}
+
+#[test]
+fn test_king_distance() {
+    assert_eq!(Square::A1.king_distance(Square::A1), 0);
+    assert_eq!(Square::A1.king_distance(Square::H8), 7);
+    assert_eq!(Square::E4.king_distance(Square::D5), 1);
+}
+
+#[test]
+fn test_center_distance() {
+    for corner in [Square::A1, Square::H1, Square::A8, Square::H8] {
+        assert_eq!(center_distance(corner), 3);
+    }
+
+    for center in [Square::D4, Square::D5, Square::E4, Square::E5] {
+        assert_eq!(center_distance(center), 0);
+    }
+
+    assert_eq!(center_distance(Square::C3), 1);
+    assert_eq!(CENTER_DISTANCE[Square::B7.to_index()], 2);
+}
+
+#[test]
+fn test_from_index() {
+    assert_eq!(Square::from_index(0), Some(Square::A1));
+    assert_eq!(Square::from_index(63), Some(Square::H8));
+    assert_eq!(Square::from_index(64), None);
+    assert_eq!(Square::from_index(usize::MAX), None);
+
+    for (index, square) in SQUARES.iter().enumerate() {
+        assert_eq!(unsafe { Square::from_index_unchecked(index) }, *square);
+    }
+}
+
+#[test]
+fn test_rank_and_file() {
+    assert_eq!(Square::E4.rank(), Rank::Fourth);
+    assert_eq!(Square::E4.file(), File::E);
+
+    for square in SQUARES {
+        assert_eq!(square.get_rank(), square.rank());
+        assert_eq!(square.get_file(), square.file());
+        assert_eq!(Square::make_square(square.rank(), square.file()), square);
+    }
+}
+
+#[test]
+fn test_directional_signatures() {
+    let _: fn(&Square, Color) -> Option<Square> = Square::forward;
+    let _: fn(&Square, Color) -> Option<Square> = Square::backward;
+    let _: fn(&Square, Color) -> Square = Square::wrapping_forward;
+    let _: fn(&Square, Color) -> Square = Square::wrapping_backward;
+}
+
+#[test]
+fn test_wrapping_forward_and_backward() {
+    assert_eq!(Square::E2.wrapping_forward(Color::White), Square::E3);
+    assert_eq!(Square::E2.wrapping_forward(Color::Black), Square::E1);
+    assert_eq!(Square::E2.wrapping_backward(Color::White), Square::E1);
+    assert_eq!(Square::E2.wrapping_backward(Color::Black), Square::E3);
+    assert_eq!(Square::E8.forward(Color::White), None);
+    assert_eq!(Square::E1.backward(Color::White), None);
+}
+
+#[test]
+fn test_flips() {
+    for square in SQUARES {
+        assert_eq!(square.flip_rank().flip_rank(), square);
+        assert_eq!(square.flip_file().flip_file(), square);
+        assert_eq!(square.flip_rank().file(), square.file());
+        assert_eq!(
+            square.flip_rank().rank().to_index(),
+            7 - square.rank().to_index()
+        );
+        assert_eq!(square.flip_file().rank(), square.rank());
+        assert_eq!(
+            square.flip_file().file().to_index(),
+            7 - square.file().to_index()
+        );
+    }
+}
+
+#[test]
+fn test_step() {
+    let expected = [
+        Square::E5,
+        Square::F5,
+        Square::F4,
+        Square::F3,
+        Square::E3,
+        Square::D3,
+        Square::D4,
+        Square::D5,
+    ];
+
+    for (direction, square) in DIRECTIONS.into_iter().zip(expected) {
+        assert_eq!(Square::E4.step(direction), Some(square));
+        assert_eq!(square.step(direction.opposite()), Some(Square::E4));
+    }
+
+    assert_eq!(Square::A1.step(Direction::South), None);
+    assert_eq!(Square::A1.step(Direction::West), None);
+    assert_eq!(Square::A1.step(Direction::NorthWest), None);
+    assert_eq!(Square::A1.step(Direction::NorthEast), Some(Square::B2));
+    assert_eq!(Square::H8.step(Direction::North), None);
+    assert_eq!(Square::H8.step(Direction::East), None);
+    assert_eq!(Square::H8.step(Direction::SouthEast), None);
+    assert_eq!(Square::H8.step(Direction::SouthWest), Some(Square::G7));
+}
+
+#[test]
+fn test_ordering() {
+    let set: BTreeSet<Square> = SQUARES.iter().rev().copied().collect();
+    assert_eq!(set.len(), 64);
+    assert!(set.into_iter().eq(SQUARES));
+
+    let mut ranks = BTreeMap::new();
+    for square in SQUARES.iter().rev() {
+        *ranks.entry(square.rank()).or_insert(0) += 1;
+    }
+    assert_eq!(ranks.keys().next(), Some(&Rank::First));
+    assert_eq!(ranks.values().sum::<i32>(), 64);
+
+    assert!(File::A < File::H);
+    assert!(Rank::First < Rank::Eighth);
+    assert!(Color::White < Color::Black);
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(Square::try_from(0u8), Ok(Square::A1));
+    assert_eq!(Square::try_from(63u8), Ok(Square::H8));
+    assert_eq!(Square::try_from(64u8), Err(Error::InvalidSquare));
+    assert_eq!(Square::try_from(255u8), Err(Error::InvalidSquare));
+
+    assert_eq!(Square::try_from(28usize), Ok(Square::E4));
+    assert_eq!(Square::try_from(64usize), Err(Error::InvalidSquare));
+
+    for square in SQUARES {
+        assert_eq!(Square::try_from(square.to_int()), Ok(square));
+        assert_eq!(Square::try_from(square.to_index()), Ok(square));
+    }
+}
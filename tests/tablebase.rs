@@ -0,0 +1,55 @@
+use chessframe::{
+    board::Board,
+    tablebase::{NoTablebase, Tablebase, Wdl},
+};
+
+/// A prober that knows every position with at most three pieces is a win.
+struct MockTablebase;
+
+impl Tablebase for MockTablebase {
+    fn max_pieces(&self) -> u32 {
+        3
+    }
+
+    fn probe_wdl(&self, board: &Board) -> Option<Wdl> {
+        if board.combined().count_ones() <= self.max_pieces() {
+            Some(Wdl::Win)
+        } else {
+            None
+        }
+    }
+
+    fn probe_dtz(&self, board: &Board) -> Option<i32> {
+        self.probe_wdl(board).map(|_| 1)
+    }
+}
+
+#[test]
+fn test_mock_tablebase() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+
+    assert_eq!(MockTablebase.probe_wdl(&board), Some(Wdl::Win));
+    assert_eq!(MockTablebase.probe_dtz(&board), Some(1));
+    assert_eq!(MockTablebase.probe_wdl(&Board::default()), None);
+    assert_eq!(MockTablebase.probe_dtz(&Board::default()), None);
+
+    let tablebase: &dyn Tablebase = &MockTablebase;
+    assert_eq!(tablebase.max_pieces(), 3);
+}
+
+#[test]
+fn test_no_tablebase() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+
+    assert_eq!(NoTablebase.max_pieces(), 0);
+    assert_eq!(NoTablebase.probe_wdl(&board), None);
+    assert_eq!(NoTablebase.probe_dtz(&board), None);
+}
+
+#[test]
+fn test_wdl_ordering() {
+    assert!(Wdl::Win > Wdl::CursedWin);
+    assert!(Wdl::CursedWin > Wdl::Draw);
+    assert!(Wdl::Draw > Wdl::BlessedLoss);
+    assert!(Wdl::BlessedLoss > Wdl::Loss);
+}
@@ -0,0 +1,336 @@
+use std::{str::FromStr, time::Duration};
+
+use chessframe::{
+    color::Color,
+    uci::{Go, Info, Score, TimeControl, Uci, UciCommand, format_multipv, respond_uci},
+};
+
+#[test]
+fn test_time_control_sudden_death() {
+    let time_control = TimeControl::default();
+    let go = Go {
+        wtime: Some(60_000),
+        btime: Some(30_000),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        time_control.allocate(&go, Color::White),
+        Duration::from_millis(2_000)
+    );
+    assert_eq!(
+        time_control.allocate(&go, Color::Black),
+        Duration::from_millis(1_000)
+    );
+
+    let go = Go {
+        wtime: Some(10_000),
+        moves_to_go: Some(5),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        time_control.allocate(&go, Color::White),
+        Duration::from_millis(2_000)
+    );
+}
+
+#[test]
+fn test_time_control_increment() {
+    let time_control = TimeControl::default();
+    let go = Go {
+        wtime: Some(30_000),
+        btime: Some(30_000),
+        winc: Some(2_000),
+        binc: Some(500),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        time_control.allocate(&go, Color::White),
+        Duration::from_millis(3_000)
+    );
+    assert_eq!(
+        time_control.allocate(&go, Color::Black),
+        Duration::from_millis(1_500)
+    );
+
+    // Never spend more than the remaining time, less the overhead.
+    let go = Go {
+        btime: Some(1_000),
+        binc: Some(2_000),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        time_control.allocate(&go, Color::Black),
+        Duration::from_millis(950)
+    );
+}
+
+#[test]
+fn test_time_control_limits() {
+    let time_control = TimeControl::default();
+
+    let go = Go {
+        wtime: Some(60_000),
+        move_time: Some(1_000),
+        ..Default::default()
+    };
+    assert_eq!(
+        time_control.allocate(&go, Color::White),
+        Duration::from_millis(950)
+    );
+
+    let go = Go {
+        infinite: true,
+        ..Default::default()
+    };
+    assert_eq!(time_control.allocate(&go, Color::White), Duration::MAX);
+
+    let go = Go {
+        infinite: true,
+        wtime: Some(60_000),
+        winc: Some(1_000),
+        ..Default::default()
+    };
+    assert_eq!(time_control.allocate(&go, Color::White), Duration::MAX);
+
+    let go = Go {
+        ponder: true,
+        wtime: Some(60_000),
+        move_time: Some(1_000),
+        ..Default::default()
+    };
+    assert_eq!(time_control.allocate(&go, Color::White), Duration::MAX);
+}
+
+#[test]
+fn test_info_builder() {
+    let info = Info::builder()
+        .depth(5)
+        .score_cp(30)
+        .pv(vec!["e2e4".into()])
+        .build();
+
+    assert_eq!(
+        info,
+        Info {
+            depth: Some(5),
+            score: Some(Score {
+                cp: Some(30),
+                ..Default::default()
+            }),
+            pv: Some("e2e4".to_string()),
+            ..Default::default()
+        }
+    );
+    assert_eq!(info.to_string(), "info depth 5 score cp 30 pv e2e4");
+
+    let info = Info::builder()
+        .depth(12)
+        .seldepth(18)
+        .nodes(100_000)
+        .nps(1_000_000)
+        .score_mate(-3)
+        .build();
+
+    assert_eq!(
+        info.to_string(),
+        "info depth 12 seldepth 18 nodes 100000 nps 1000000 score mate -3"
+    );
+}
+
+#[test]
+fn test_respond_uci() {
+    assert_eq!(
+        respond_uci("Engine", "Author"),
+        vec![
+            UciCommand::Id {
+                name: "Engine".to_string(),
+                author: "Author".to_string(),
+            },
+            UciCommand::UciOk,
+        ]
+    );
+}
+
+struct MockEngine {
+    commands: Vec<UciCommand>,
+    sent: Vec<UciCommand>,
+    handled: usize,
+    quitting: bool,
+}
+
+impl Uci for MockEngine {
+    fn send_command(&mut self, command: UciCommand) {
+        self.sent.push(command);
+    }
+
+    fn read_command(&mut self) -> Option<UciCommand> {
+        if self.commands.is_empty() {
+            None
+        } else {
+            Some(self.commands.remove(0))
+        }
+    }
+
+    fn handle_command(&mut self) {
+        self.handled += 1;
+
+        match self.read_command() {
+            Some(UciCommand::Uci) => {
+                for command in respond_uci("Mock", "Author") {
+                    self.send_command(command);
+                }
+            }
+            Some(UciCommand::Quit) => self.quitting = true,
+            _ => {}
+        }
+    }
+
+    fn should_quit(&self) -> bool {
+        self.quitting
+    }
+}
+
+#[test]
+fn test_run() {
+    let mut engine = MockEngine {
+        commands: vec![UciCommand::Quit, UciCommand::Uci],
+        sent: Vec::new(),
+        handled: 0,
+        quitting: false,
+    };
+
+    engine.run();
+
+    assert_eq!(engine.handled, 1);
+    assert!(engine.sent.is_empty());
+    assert_eq!(engine.commands, vec![UciCommand::Uci]);
+
+    let mut engine = MockEngine {
+        commands: vec![UciCommand::Uci, UciCommand::Quit],
+        sent: Vec::new(),
+        handled: 0,
+        quitting: false,
+    };
+
+    engine.run();
+
+    assert_eq!(engine.handled, 2);
+    assert_eq!(engine.sent, respond_uci("Mock", "Author"));
+}
+
+#[test]
+fn test_go_searchmoves() {
+    let command = UciCommand::from_str("go searchmoves e2e4 d2d4 depth 8");
+
+    assert_eq!(
+        command,
+        Ok(UciCommand::Go(Go {
+            searchmoves: Some(vec!["e2e4".to_string(), "d2d4".to_string()]),
+            depth: Some(8),
+            ..Default::default()
+        }))
+    );
+
+    let command = UciCommand::from_str("go wtime 1000 searchmoves g1f3");
+
+    assert_eq!(
+        command,
+        Ok(UciCommand::Go(Go {
+            searchmoves: Some(vec!["g1f3".to_string()]),
+            wtime: Some(1000),
+            ..Default::default()
+        }))
+    );
+}
+
+#[test]
+fn test_parse_leading_junk() {
+    assert_eq!(UciCommand::from_str("  \tuci"), Ok(UciCommand::Uci));
+    assert_eq!(UciCommand::from_str("isready\n"), Ok(UciCommand::IsReady));
+
+    assert_eq!(
+        UciCommand::from_str("junk position startpos moves e2e4"),
+        Ok(UciCommand::Position {
+            fen: "startpos".to_string(),
+            moves: Some(vec!["e2e4".to_string()]),
+        })
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = UciCommand::from_str("hello world").unwrap_err();
+    assert!(error.contains("hello world"));
+
+    let error = UciCommand::from_str("position nowhere").unwrap_err();
+    assert!(error.contains("position nowhere"));
+}
+
+#[test]
+fn test_score_ordering() {
+    let mut scores = vec![
+        Score::cp(50),
+        Score::mate(3),
+        Score::mate(-2),
+        Score::cp(-100),
+    ];
+    scores.sort();
+
+    assert_eq!(
+        scores,
+        vec![
+            Score::mate(-2),
+            Score::cp(-100),
+            Score::cp(50),
+            Score::mate(3)
+        ]
+    );
+
+    assert!(Score::mate(1) > Score::mate(5));
+    assert!(Score::mate(-1) < Score::mate(-5));
+    assert!(Score::mate(1) > Score::cp(100_000));
+    assert!(Score::default() < Score::mate(-1));
+    assert_eq!(scores.iter().max(), Some(&Score::mate(3)));
+}
+
+#[test]
+fn test_format_multipv() {
+    let lines = [
+        Info::builder()
+            .depth(10)
+            .score_cp(35)
+            .pv(vec!["e2e4".into(), "e7e5".into(), "g1f3".into()])
+            .build(),
+        Info::builder()
+            .depth(10)
+            .score_cp(30)
+            .pv(vec!["d2d4".into(), "d7d5".into()])
+            .build(),
+        Info::builder()
+            .depth(10)
+            .multipv(3)
+            .score_mate(-4)
+            .pv(vec!["f2f3".into()])
+            .build(),
+    ];
+
+    assert_eq!(
+        format_multipv(&lines),
+        "info depth 10 multipv 1 score cp 35 pv e2e4 e7e5 g1f3\n\
+         info depth 10 multipv 2 score cp 30 pv d2d4 d7d5\n\
+         info depth 10 multipv 3 score mate -4 pv f2f3"
+    );
+
+    for (line, info) in format_multipv(&lines).lines().zip(1..) {
+        let Ok(UciCommand::Info(parsed)) = line.parse() else {
+            panic!("{}", line);
+        };
+
+        assert_eq!(parsed.multipv, Some(info));
+        assert_eq!(parsed.pv, lines[info - 1].pv);
+    }
+}